@@ -0,0 +1,128 @@
+use std::{collections::BTreeMap, fs, path::Path};
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tracing::info;
+
+use crate::{aoc::Answer, input::InputSource};
+
+pub const ANSWERS_FILE: &str = "answers.json";
+
+// inputs are identified by content so a renamed or re-downloaded file keeps its answers
+pub fn input_id (lines: &[String]) -> String {
+    let mut hasher = Sha256::new();
+    for line in lines {
+        hasher.update(line.as_bytes());
+        hasher.update(b"\n");
+    }
+    hasher.finalize().iter().take(8).map(|b| format!("{:02x}", b)).collect()
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct StoredAnswers {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part_1: Option<Answer>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part_2: Option<Answer>,
+}
+
+impl StoredAnswers {
+    pub fn parts (&self) -> Vec<u8> {
+        [1, 2].into_iter().filter(|part| self.get(*part).is_some()).collect()
+    }
+
+    pub fn get (&self, part: u8) -> Option<&Answer> {
+        match part {
+            1 => self.part_1.as_ref(),
+            2 => self.part_2.as_ref(),
+            _ => None,
+        }
+    }
+}
+
+// year -> day -> input id -> answers
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct AnswerStore {
+    years: BTreeMap<u32, BTreeMap<u32, BTreeMap<String, StoredAnswers>>>,
+}
+
+impl AnswerStore {
+    pub fn load (path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Ok(AnswerStore::default());
+        }
+        let contents = fs::read_to_string(path).with_context(|| format!("failed to read answers {}", path.display()))?;
+        serde_json::from_str(&contents).with_context(|| format!("failed to parse answers {}", path.display()))
+    }
+
+    pub fn save (&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent).with_context(|| format!("failed to create {}", parent.display()))?;
+        }
+        let json = serde_json::to_string_pretty(self)?;
+        fs::write(path, json).with_context(|| format!("failed to write answers {}", path.display()))
+    }
+
+    pub fn get (&self, year: u32, day: u32, input_id: &str) -> Option<&StoredAnswers> {
+        self.years.get(&year)?.get(&day)?.get(input_id)
+    }
+
+    // every recorded input of the year in day order, as (day, input id, answers)
+    pub fn recorded (&self, year: u32) -> Vec<(u32, &str, &StoredAnswers)> {
+        let Some(days) = self.years.get(&year) else { return Vec::new() };
+        days.iter()
+            .flat_map(|(day, inputs)| inputs.iter().map(move |(id, stored)| (*day, id.as_str(), stored)))
+            .collect()
+    }
+
+    pub fn expected (&self, year: u32, day: u32, input_id: &str, part: u8) -> Option<&Answer> {
+        self.get(year, day, input_id)?.get(part)
+    }
+
+    // only real answers get locked in, unsolved parts and errors are ignored; returns whether anything was stored.
+    // A locked in answer is never replaced by a different one, that's a drift to look into or to `forget` first
+    pub fn record (&mut self, year: u32, day: u32, input_id: &str, source: Option<&str>, part: u8, answer: &Answer) -> Result<bool> {
+        if !answer.is_solved() || !(1..=2).contains(&part) {
+            return Ok(false);
+        }
+        if let Some(expected) = self.expected(year, day, input_id, part).filter(|expected| *expected != answer) {
+            bail!("day {} part {} is recorded as {} for input {}, not {}", day, part, expected, input_id, answer);
+        }
+        let inputs = self.years.entry(year).or_default().entry(day).or_default();
+        // the file was edited since, its old answers could never be verified again
+        if let Some(source) = source.filter(|source| *source != InputSource::Stdin.to_string()) {
+            inputs.retain(|id, stored| {
+                let replaced = id != input_id && stored.source.as_deref() == Some(source);
+                if replaced {
+                    info!("dropped the day {} answers recorded for an earlier version of {} ({})", day, source, id);
+                }
+                !replaced
+            });
+        }
+        let stored = inputs.entry(input_id.to_string()).or_default();
+        if let Some(source) = source {
+            stored.source = Some(source.to_string());
+        }
+        match part {
+            1 => stored.part_1 = Some(answer.clone()),
+            _ => stored.part_2 = Some(answer.clone()),
+        }
+        Ok(true)
+    }
+
+    // unlocks a recorded answer so a different one can be recorded, returns what was recorded
+    pub fn forget (&mut self, year: u32, day: u32, input_id: &str, part: u8) -> Option<Answer> {
+        let stored = self.years.get_mut(&year)?.get_mut(&day)?.get_mut(input_id)?;
+        match part {
+            1 => stored.part_1.take(),
+            2 => stored.part_2.take(),
+            _ => None,
+        }
+    }
+}
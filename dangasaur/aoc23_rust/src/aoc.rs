@@ -1,11 +1,178 @@
-use anyhow::Result;
-
-#[derive(Debug)]
-pub struct Solution {
-    pub part_1: String,
-    pub part_2: String,
-}
-
-pub trait Solveable {
-    fn solve (&self, lines: &Vec<String>) -> Result<Solution>;
-}
+use std::{fmt, str::FromStr};
+
+use anyhow::Result;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[derive(Debug, Clone)]
+pub enum Answer {
+    Signed(i64),
+    Unsigned(u64),
+    Text(String),
+    Unsolved,
+    Error(String),
+}
+
+impl Answer {
+    pub fn is_solved(&self) -> bool {
+        !matches!(self, Answer::Unsolved | Answer::Error(_))
+    }
+
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Answer::Signed(_) => "signed",
+            Answer::Unsigned(_) => "unsigned",
+            Answer::Text(_) => "text",
+            Answer::Unsolved => "unsolved",
+            Answer::Error(_) => "error",
+        }
+    }
+
+    // widen both integer flavors so 46 and 46u64 compare equal
+    pub fn as_i128(&self) -> Option<i128> {
+        match self {
+            Answer::Signed(n) => Some(*n as i128),
+            Answer::Unsigned(n) => Some(*n as i128),
+            _ => None,
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        if let (Some(a), Some(b)) = (self.as_i128(), other.as_i128()) {
+            return a == b;
+        }
+        match (self, other) {
+            (Answer::Text(a), Answer::Text(b)) => a == b,
+            (Answer::Unsolved, Answer::Unsolved) => true,
+            (Answer::Error(a), Answer::Error(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Signed(n) => write!(f, "{}", n),
+            Answer::Unsigned(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+            Answer::Unsolved => write!(f, "UNSOLVED"),
+            Answer::Error(reason) => write!(f, "error: {}", reason),
+        }
+    }
+}
+
+// stored expectations and CLI input come in as text, numbers win over strings
+impl FromStr for Answer {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let s = s.trim();
+        if s == "UNSOLVED" {
+            Ok(Answer::Unsolved)
+        } else if let Ok(n) = s.parse::<u64>() {
+            Ok(Answer::Unsigned(n))
+        } else if let Ok(n) = s.parse::<i64>() {
+            Ok(Answer::Signed(n))
+        } else {
+            Ok(Answer::Text(s.to_string()))
+        }
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(n: i32) -> Self { Answer::Signed(n.into()) }
+}
+
+impl From<i64> for Answer {
+    fn from(n: i64) -> Self { Answer::Signed(n) }
+}
+
+impl From<u32> for Answer {
+    fn from(n: u32) -> Self { Answer::Unsigned(n.into()) }
+}
+
+impl From<u64> for Answer {
+    fn from(n: u64) -> Self { Answer::Unsigned(n) }
+}
+
+impl From<usize> for Answer {
+    fn from(n: usize) -> Self { Answer::Unsigned(n as u64) }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self { Answer::Text(s) }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self { Answer::Text(s.to_string()) }
+}
+
+// numbers serialize as JSON numbers, unsolved as null and errors as {"error": reason}
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        match self {
+            Answer::Signed(n) => serializer.serialize_i64(*n),
+            Answer::Unsigned(n) => serializer.serialize_u64(*n),
+            Answer::Text(s) => serializer.serialize_str(s),
+            Answer::Unsolved => serializer.serialize_none(),
+            Answer::Error(reason) => RawAnswer::Error { error: reason.clone() }.serialize(serializer),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum RawAnswer {
+    Unsigned(u64),
+    Signed(i64),
+    Text(String),
+    Error { error: String },
+    Unsolved,
+}
+
+impl<'de> Deserialize<'de> for Answer {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        Ok(match Option::<RawAnswer>::deserialize(deserializer)? {
+            Some(RawAnswer::Unsigned(n)) => Answer::Unsigned(n),
+            Some(RawAnswer::Signed(n)) => Answer::Signed(n),
+            Some(RawAnswer::Text(s)) => Answer::Text(s),
+            Some(RawAnswer::Error { error }) => Answer::Error(error),
+            Some(RawAnswer::Unsolved) | None => Answer::Unsolved,
+        })
+    }
+}
+
+// sample input from the puzzle text and the answers it produces, None when the sample doesn't cover a part
+#[derive(Debug)]
+pub struct Example {
+    pub input: &'static str,
+    pub part_1: Option<&'static str>,
+    pub part_2: Option<&'static str>,
+}
+
+impl Example {
+    pub fn lines (&self) -> Vec<String> {
+        self.input.lines().map(String::from).collect()
+    }
+
+    pub fn expected (&self, part: u8) -> Option<Answer> {
+        let expected = match part {
+            1 => self.part_1,
+            2 => self.part_2,
+            _ => None,
+        };
+        expected.map(|e| e.parse::<Answer>().unwrap_or_else(|never| match never {}))
+    }
+}
+
+pub trait Solveable {
+    type Input;
+
+    const EXAMPLES: &'static [Example] = &[];
+
+    fn parse (&self, lines: &[String]) -> Result<Self::Input>;
+    fn part_1 (&self, input: &Self::Input) -> Result<Answer>;
+    fn part_2 (&self, input: &Self::Input) -> Result<Answer>;
+}
@@ -0,0 +1,198 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    hint::black_box,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::registry::DayRegistration;
+
+pub const BASELINE_FILE: &str = "bench/baseline.json";
+
+#[derive(Debug, Clone, Copy)]
+pub struct BenchConfig {
+    pub warmup: usize,
+    pub iterations: usize,
+}
+
+// all timings are in nanoseconds so the baseline file stays plain numbers
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Stats {
+    pub min: f64,
+    pub median: f64,
+    pub mean: f64,
+    pub stddev: f64,
+}
+
+impl Stats {
+    pub fn from_samples (samples: &[Duration]) -> Self {
+        let mut nanos: Vec<f64> = samples.iter().map(|d| d.as_nanos() as f64).collect();
+        nanos.sort_by(|a, b| a.total_cmp(b));
+
+        let n = nanos.len();
+        let mean = nanos.iter().sum::<f64>() / n as f64;
+        // for odd n both indexes land on the middle sample
+        let median = (nanos[(n - 1) / 2] + nanos[n / 2]) / 2.0;
+        let variance = if n > 1 {
+            nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1) as f64
+        } else {
+            0.0
+        };
+
+        Stats { min: nanos[0], median, mean, stddev: variance.sqrt() }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BenchReport {
+    pub year: u32,
+    pub day: u32,
+    pub iterations: usize,
+    pub parse: Stats,
+    // None when the part wasn't selected, e.g. `bench 3.1`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part_1: Option<Stats>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part_2: Option<Stats>,
+}
+
+impl BenchReport {
+    pub fn key (&self) -> String {
+        format!("{}/day{}", self.year, self.day)
+    }
+
+    fn phases (&self) -> Vec<(&'static str, &Stats)> {
+        let parts = [("part 1", &self.part_1), ("part 2", &self.part_2)].into_iter()
+            .filter_map(|(phase, stats)| Some((phase, stats.as_ref()?)));
+        std::iter::once(("parse", &self.parse)).chain(parts).collect()
+    }
+}
+
+fn sample<T> (config: &BenchConfig, mut f: impl FnMut() -> Result<T>) -> Result<Stats> {
+    for _ in 0..config.warmup {
+        black_box(f()?);
+    }
+
+    let mut samples = Vec::with_capacity(config.iterations);
+    for _ in 0..config.iterations {
+        let start = Instant::now();
+        black_box(f()?);
+        samples.push(start.elapsed());
+    }
+    Ok(Stats::from_samples(&samples))
+}
+
+pub fn bench_day (registration: &DayRegistration, lines: &[String], parts: &[u8], config: &BenchConfig) -> Result<BenchReport> {
+    let config = BenchConfig { iterations: config.iterations.max(1), ..*config };
+    let parse = sample(&config, || registration.parse(lines)).context("parse failed while benchmarking")?;
+
+    // the parts share one parsed input, same as a normal run
+    let input = registration.parse(lines)?;
+    let bench_part = |part: u8| -> Result<Option<Stats>> {
+        if !parts.contains(&part) {
+            return Ok(None);
+        }
+        let stats = sample(&config, || registration.part(part, &input))
+            .with_context(|| format!("part {} failed while benchmarking", part))?;
+        Ok(Some(stats))
+    };
+    let part_1 = bench_part(1)?;
+    let part_2 = bench_part(2)?;
+
+    Ok(BenchReport { year: registration.year, day: registration.day, iterations: config.iterations, parse, part_1, part_2 })
+}
+
+fn format_nanos (nanos: f64) -> String {
+    format!("{:.3?}", Duration::from_nanos(nanos.round() as u64))
+}
+
+pub fn render (report: &BenchReport) -> String {
+    let mut lines = vec![format!(
+        "{} day {} ({} iterations)\n{:<8} {:>12} {:>12} {:>12} {:>12}",
+        report.year, report.day, report.iterations, "phase", "min", "median", "mean", "stddev"
+    )];
+    for (phase, stats) in report.phases() {
+        lines.push(format!(
+            "{:<8} {:>12} {:>12} {:>12} {:>12}",
+            phase,
+            format_nanos(stats.min),
+            format_nanos(stats.median),
+            format_nanos(stats.mean),
+            format_nanos(stats.stddev),
+        ));
+    }
+    lines.join("\n")
+}
+
+#[derive(Debug)]
+pub struct Regression {
+    pub phase: &'static str,
+    pub baseline_median: f64,
+    pub current_median: f64,
+}
+
+impl Regression {
+    pub fn change_pct (&self) -> f64 {
+        (self.current_median - self.baseline_median) / self.baseline_median * 100.0
+    }
+}
+
+// a phase regresses when its median is more than threshold_pct slower than the baseline median
+pub fn compare (current: &BenchReport, baseline: &BenchReport, threshold_pct: f64) -> Vec<Regression> {
+    let baseline_phases = baseline.phases();
+    current.phases().into_iter()
+        .filter_map(|(phase, now)| {
+            let (_, then) = baseline_phases.iter().find(|(p, _)| *p == phase)?;
+            let regression = Regression { phase, baseline_median: then.median, current_median: now.median };
+            if then.median > 0.0 && regression.change_pct() > threshold_pct {
+                Some(regression)
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+pub fn render_regression (regression: &Regression) -> String {
+    format!(
+        "REGRESSION {}: median {} -> {} (+{:.1}%)",
+        regression.phase,
+        format_nanos(regression.baseline_median),
+        format_nanos(regression.current_median),
+        regression.change_pct(),
+    )
+}
+
+// baselines for every day share one file keyed by "<year>/dayN"
+pub type Baselines = BTreeMap<String, BenchReport>;
+
+pub fn load_baselines (path: impl AsRef<Path>) -> Result<Baselines> {
+    let path = path.as_ref();
+    if !path.exists() {
+        return Ok(Baselines::new());
+    }
+    let contents = fs::read_to_string(path).with_context(|| format!("failed to read baseline {}", path.display()))?;
+    serde_json::from_str(&contents).with_context(|| format!("failed to parse baseline {}", path.display()))
+}
+
+pub fn save_baseline (path: impl AsRef<Path>, report: &BenchReport) -> Result<()> {
+    let path = path.as_ref();
+    let mut baselines = load_baselines(path)?;
+    // benching a single part keeps the other part's baseline
+    let mut report = report.clone();
+    if let Some(previous) = baselines.get(&report.key()) {
+        report.part_1 = report.part_1.or_else(|| previous.part_1.clone());
+        report.part_2 = report.part_2.or_else(|| previous.part_2.clone());
+    }
+    baselines.insert(report.key(), report);
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).with_context(|| format!("failed to create {}", parent.display()))?;
+    }
+    let json = serde_json::to_string_pretty(&baselines)?;
+    fs::write(path, json).with_context(|| format!("failed to write baseline {}", path.display()))
+}
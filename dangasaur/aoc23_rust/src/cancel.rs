@@ -0,0 +1,61 @@
+use std::{cell::RefCell, error::Error, fmt, process};
+
+use tokio_util::sync::CancellationToken;
+use tracing::warn;
+
+thread_local! {
+    // the token of the solver running on this thread, None outside a limited run
+    static TOKEN: RefCell<Option<CancellationToken>> = const { RefCell::new(None) };
+}
+
+// what check() fails with once the run was timed out or interrupted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cancelled;
+
+impl fmt::Display for Cancelled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "cancelled")
+    }
+}
+
+impl Error for Cancelled {}
+
+// runs f with token as this thread's cancellation token, nested calls get the outer one back afterwards
+pub fn with_token<T> (token: CancellationToken, f: impl FnOnce() -> T) -> T {
+    let outer = TOKEN.with(|current| current.replace(Some(token)));
+    let result = f();
+    TOKEN.with(|current| current.replace(outer));
+    result
+}
+
+pub fn is_cancelled () -> bool {
+    TOKEN.with(|current| current.borrow().as_ref().is_some_and(|t| t.is_cancelled()))
+}
+
+/// For solvers to poll from long loops, `cancel::check()?` gives up once the run was timed out or interrupted.
+/// Outside a limited run (tests, watch, bench) it never fails.
+pub fn check () -> Result<(), Cancelled> {
+    if is_cancelled() {
+        Err(Cancelled)
+    } else {
+        Ok(())
+    }
+}
+
+// the first ctrl-c cancels the returned token so the run can report what it was doing,
+// a second one gives up on solvers that never poll and exits straight away
+pub fn interrupt_on_ctrl_c () -> CancellationToken {
+    let interrupt = CancellationToken::new();
+    let token = interrupt.clone();
+    tokio::spawn(async move {
+        if tokio::signal::ctrl_c().await.is_err() {
+            return;
+        }
+        token.cancel();
+        if tokio::signal::ctrl_c().await.is_ok() {
+            warn!("interrupted again, exiting");
+            process::exit(130);
+        }
+    });
+    interrupt
+}
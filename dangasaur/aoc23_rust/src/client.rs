@@ -0,0 +1,105 @@
+use std::{env, fs, path::PathBuf};
+
+use anyhow::{bail, Context, Result};
+
+use crate::aoc::Answer;
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+pub const SESSION_ENV: &str = "AOC_SESSION";
+pub const SESSION_FILE: &str = ".aoc-session";
+
+// Eric asks automated tools to identify themselves and point back at the source
+pub const USER_AGENT: &str = concat!(
+    "github.com/dangasaur/AdventOfCode2023 ",
+    env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"),
+);
+
+// places the session cookie value is looked up, in order, after the AOC_SESSION env var
+pub fn session_files () -> Vec<PathBuf> {
+    let mut files = vec![PathBuf::from(SESSION_FILE)];
+    if let Some(home) = env::var_os("HOME") {
+        files.push(PathBuf::from(home).join(".config").join("aoc").join("session"));
+    }
+    files
+}
+
+pub fn session_token () -> Result<String> {
+    if let Ok(token) = env::var(SESSION_ENV) {
+        if !token.trim().is_empty() {
+            return Ok(token.trim().to_string());
+        }
+    }
+
+    for file in session_files() {
+        if let Ok(token) = fs::read_to_string(&file) {
+            if !token.trim().is_empty() {
+                return Ok(token.trim().to_string());
+            }
+        }
+    }
+
+    let files: Vec<String> = session_files().iter().map(|f| f.display().to_string()).collect();
+    bail!("no session token, set {} or put the session cookie value in one of: {}", SESSION_ENV, files.join(", "))
+}
+
+pub struct AocClient {
+    base_url: String,
+    session: String,
+    http: reqwest::Client,
+}
+
+impl AocClient {
+    pub fn new (base_url: &str, session: &str) -> Result<Self> {
+        let http = reqwest::Client::builder()
+            .user_agent(USER_AGENT)
+            .build()
+            .context("failed to build http client")?;
+        Ok(AocClient { base_url: base_url.trim_end_matches('/').to_string(), session: session.to_string(), http })
+    }
+
+    // real site unless AOC_BASE_URL points somewhere else, session from env or config
+    pub fn from_env () -> Result<Self> {
+        let base_url = env::var(BASE_URL_ENV).unwrap_or_else(|_| BASE_URL.to_string());
+        AocClient::new(&base_url, &session_token()?)
+    }
+
+    fn cookie (&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    pub async fn fetch_input (&self, year: u32, day: u32) -> Result<String> {
+        let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
+        let response = self.http.get(&url)
+            .header(reqwest::header::COOKIE, self.cookie())
+            .send()
+            .await
+            .with_context(|| format!("failed to request {}", url))?;
+
+        let status = response.status();
+        let body = response.text().await.with_context(|| format!("failed to read response from {}", url))?;
+        if !status.is_success() {
+            bail!("{} returned {}: {}", url, status, body.trim());
+        }
+        Ok(body)
+    }
+
+    // returns the raw result page, the submit module works out what it means
+    pub async fn submit_answer (&self, year: u32, day: u32, part: u8, answer: &Answer) -> Result<String> {
+        let url = format!("{}/{}/day/{}/answer", self.base_url, year, day);
+        let form = [("level", part.to_string()), ("answer", answer.to_string())];
+        let response = self.http.post(&url)
+            .header(reqwest::header::COOKIE, self.cookie())
+            .form(&form)
+            .send()
+            .await
+            .with_context(|| format!("failed to post to {}", url))?;
+
+        let status = response.status();
+        let body = response.text().await.with_context(|| format!("failed to read response from {}", url))?;
+        if !status.is_success() {
+            bail!("{} returned {}: {}", url, status, body.trim());
+        }
+        Ok(body)
+    }
+}
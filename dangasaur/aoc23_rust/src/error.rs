@@ -0,0 +1,136 @@
+use std::{error::Error, fmt, ops::Range};
+
+// a problem with the puzzle input, pointing at the exact line and columns that caused it
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AocError {
+    pub year: Option<u32>,
+    pub day: Option<u32>,
+    pub part: Option<u8>,
+    /// 1-based input line number
+    pub line: Option<usize>,
+    /// byte range within the line
+    pub span: Option<Range<usize>>,
+    pub text: Option<String>,
+    pub message: String,
+}
+
+impl AocError {
+    pub fn new (message: impl Into<String>) -> Self {
+        AocError { message: message.into(), ..Default::default() }
+    }
+
+    /// `index` is the 0-based position in the input lines, the whole line is highlighted until a span is set
+    pub fn at_line (mut self, index: usize, text: &str) -> Self {
+        self.line = Some(index + 1);
+        self.span = Some(0..text.len());
+        self.text = Some(text.to_string());
+        self
+    }
+
+    pub fn span (mut self, span: Range<usize>) -> Self {
+        self.span = Some(span);
+        self
+    }
+
+    // fills in whatever the solver didn't know about itself
+    pub fn locate (mut self, year: u32, day: u32, part: Option<u8>) -> Self {
+        self.year = self.year.or(Some(year));
+        self.day = self.day.or(Some(day));
+        self.part = self.part.or(part);
+        self
+    }
+
+    fn location (&self) -> String {
+        let mut location = Vec::new();
+        if let Some(day) = self.day {
+            location.push(format!("day {}", day));
+        }
+        if let Some(part) = self.part {
+            location.push(format!("part {}", part));
+        }
+        if let Some(line) = self.line {
+            location.push(format!("line {}", line));
+        }
+        if let (Some(span), Some(text)) = (&self.span, &self.text) {
+            let (start, end) = columns(text, span);
+            if end > start + 1 {
+                location.push(format!("columns {}-{}", start + 1, end));
+            } else {
+                location.push(format!("column {}", start + 1));
+            }
+        }
+        location.join(", ")
+    }
+
+    /// the message and where it happened on one line, for tables and records that can't hold the snippet
+    pub fn short (&self) -> String {
+        let location = self.location();
+        if location.is_empty() {
+            self.message.clone()
+        } else {
+            format!("{} ({})", self.message, location)
+        }
+    }
+
+    /// the offending input line with carets under the span
+    pub fn snippet (&self) -> Option<String> {
+        let (line, text) = (self.line?, self.text.as_ref()?);
+        let span = self.span.clone().unwrap_or(0..text.len());
+        let (start, end) = columns(text, &span);
+        let gutter = " ".repeat(line.to_string().len());
+        Some(format!(
+            "{} |\n{} | {}\n{} | {}{}",
+            gutter,
+            line, text,
+            gutter, " ".repeat(start), "^".repeat((end - start).max(1)),
+        ))
+    }
+}
+
+// converts a byte span into char columns so carets line up, clamped to the line
+fn columns (text: &str, span: &Range<usize>) -> (usize, usize) {
+    let clamp = |i: usize| {
+        let mut i = i.min(text.len());
+        while !text.is_char_boundary(i) {
+            i -= 1;
+        }
+        text[..i].chars().count()
+    };
+    let start = clamp(span.start);
+    (start, clamp(span.end).max(start))
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)?;
+        let location = self.location();
+        if !location.is_empty() {
+            write!(f, "\n --> {}", location)?;
+        }
+        if let Some(snippet) = self.snippet() {
+            write!(f, "\n{}", snippet)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for AocError {}
+
+// tags an AocError anywhere in the chain with the day and part it came from, other errors pass through
+pub fn locate (mut error: anyhow::Error, year: u32, day: u32, part: Option<u8>) -> anyhow::Error {
+    if let Some(aoc_error) = error.downcast_mut::<AocError>() {
+        *aoc_error = aoc_error.clone().locate(year, day, part);
+    }
+    error
+}
+
+// the whole chain on one line like `{:#}` does, but an AocError only gives its message and location
+pub fn summary (error: &anyhow::Error) -> String {
+    error.chain()
+        .map(|cause| match cause.downcast_ref::<AocError>() {
+            Some(aoc_error) => aoc_error.short(),
+            None => cause.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(": ")
+}
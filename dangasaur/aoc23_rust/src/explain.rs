@@ -0,0 +1,131 @@
+use std::cell::RefCell;
+
+use anyhow::Result;
+use clap::ValueEnum;
+use serde::Serialize;
+
+use crate::{aoc::Answer, registry::DayRegistration};
+
+// one thing a solver did on the way to its answer, e.g. subject "line 3", detail "first digit 1, last digit 5 => 15"
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Step {
+    pub subject: String,
+    pub detail: String,
+}
+
+thread_local! {
+    // Some while an explanation is being recorded on this thread
+    static STEPS: RefCell<Option<Vec<Step>>> = const { RefCell::new(None) };
+}
+
+pub fn enabled () -> bool {
+    STEPS.with(|steps| steps.borrow().is_some())
+}
+
+pub fn step (subject: impl Into<String>, detail: impl Into<String>) {
+    STEPS.with(|steps| {
+        if let Some(steps) = steps.borrow_mut().as_mut() {
+            steps.push(Step { subject: subject.into(), detail: detail.into() });
+        }
+    });
+}
+
+// runs f with recording switched on and hands back whatever steps it explained, nested calls keep their own steps
+pub fn record<T> (f: impl FnOnce() -> T) -> (T, Vec<Step>) {
+    let outer = STEPS.with(|steps| steps.replace(Some(Vec::new())));
+    let result = f();
+    let steps = STEPS.with(|steps| steps.replace(outer)).unwrap_or_default();
+    (result, steps)
+}
+
+/// Records an explanation step, e.g. `explain!(format!("game {}", id), "max red {}", red);`
+/// The detail is only formatted while an explanation is being recorded, so solvers can call it from hot loops.
+#[macro_export]
+macro_rules! explain {
+    ($subject:expr, $($detail:tt)+) => {
+        if $crate::explain::enabled() {
+            $crate::explain::step($subject, format!($($detail)+));
+        }
+    };
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ExplainFormat {
+    Text,
+    Markdown,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PartExplanation {
+    pub part: u8,
+    pub answer: Answer,
+    pub steps: Vec<Step>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DayExplanation {
+    pub year: u32,
+    pub day: u32,
+    pub title: &'static str,
+    pub parts: Vec<PartExplanation>,
+}
+
+// solves the given parts with recording on, a failing part keeps the steps it got through
+pub fn explain_day (registration: &DayRegistration, lines: &[String], parts: &[u8]) -> Result<DayExplanation> {
+    let input = registration.parse(lines)?;
+    let parts = parts.iter().copied()
+        .map(|part| {
+            let (answer, steps) = record(|| registration.part(part, &input));
+            let answer = answer.unwrap_or_else(|e| Answer::Error(format!("{:#}", e)));
+            PartExplanation { part, answer, steps }
+        })
+        .collect();
+    Ok(DayExplanation { year: registration.year, day: registration.day, title: registration.title, parts })
+}
+
+pub fn render (explanation: &DayExplanation, format: ExplainFormat) -> String {
+    match format {
+        ExplainFormat::Text => render_text(explanation),
+        ExplainFormat::Markdown => render_markdown(explanation),
+    }
+}
+
+pub fn render_text (explanation: &DayExplanation) -> String {
+    let mut lines = vec![format!("day {}: {}", explanation.day, explanation.title)];
+    for part in &explanation.parts {
+        lines.push(format!("part {}", part.part));
+        if part.steps.is_empty() {
+            lines.push("  (no steps explained)".to_string());
+        }
+        for step in &part.steps {
+            lines.push(format!("  {}: {}", step.subject, step.detail));
+        }
+        lines.push(format!("  answer: {}", part.answer));
+    }
+    lines.join("\n")
+}
+
+fn markdown_cell (text: &str) -> String {
+    text.replace('|', "\\|")
+}
+
+pub fn render_markdown (explanation: &DayExplanation) -> String {
+    let mut lines = vec![format!("# Day {}: {}", explanation.day, explanation.title)];
+    for part in &explanation.parts {
+        lines.push(String::new());
+        lines.push(format!("## Part {}", part.part));
+        lines.push(String::new());
+        if part.steps.is_empty() {
+            lines.push("_No steps explained._".to_string());
+        } else {
+            lines.push("| step | explanation |".to_string());
+            lines.push("|---|---|".to_string());
+            for step in &part.steps {
+                lines.push(format!("| {} | {} |", markdown_cell(&step.subject), markdown_cell(&step.detail)));
+            }
+        }
+        lines.push(String::new());
+        lines.push(format!("**Answer:** `{}`", part.answer));
+    }
+    lines.join("\n")
+}
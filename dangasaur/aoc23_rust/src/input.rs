@@ -0,0 +1,123 @@
+use std::{
+    env, fmt,
+    fs::{self, File},
+    io::{self, prelude::*},
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result};
+use flate2::read::GzDecoder;
+
+use crate::client::AocClient;
+
+pub const INPUT_DIR: &str = "input";
+
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::Stdin => write!(f, "<stdin>"),
+            InputSource::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+impl InputSource {
+    // "-" means stdin, anything else is resolved as a path
+    pub fn from_arg (arg: &Path, input_dir: impl AsRef<Path>) -> Self {
+        if arg == Path::new("-") {
+            InputSource::Stdin
+        } else {
+            InputSource::File(resolve_path(arg, input_dir))
+        }
+    }
+}
+
+// inputs live under input/<year>/dayN.txt so several events can share one checkout
+pub fn input_path (input_dir: impl AsRef<Path>, year: u32, day: u32) -> PathBuf {
+    input_dir.as_ref().join(year.to_string()).join(format!("day{}.txt", day))
+}
+
+// an empty file is a placeholder (see new-day) rather than a downloaded input
+pub fn is_cached (path: &Path) -> bool {
+    fs::metadata(path).is_ok_and(|m| m.len() > 0)
+}
+
+// expands a leading ~ and falls back to the input dir for relative paths that don't exist from the cwd,
+// so `run 5 2023/day5.txt` finds input/2023/day5.txt
+pub fn resolve_path (path: &Path, input_dir: impl AsRef<Path>) -> PathBuf {
+    let path = match (path.strip_prefix("~"), env::var_os("HOME")) {
+        (Ok(rest), Some(home)) => PathBuf::from(home).join(rest),
+        _ => path.to_path_buf(),
+    };
+
+    if path.is_relative() && !path.exists() {
+        let in_input_dir = input_dir.as_ref().join(&path);
+        if in_input_dir.exists() {
+            return in_input_dir;
+        }
+    }
+    path
+}
+
+// gzip is detected by its magic bytes, CRLF and a leading BOM are normalized away for our Windows editors
+pub fn decode (bytes: Vec<u8>, source: &InputSource) -> Result<Vec<String>> {
+    let bytes = if bytes.starts_with(&GZIP_MAGIC) {
+        let mut decoded = Vec::new();
+        GzDecoder::new(bytes.as_slice())
+            .read_to_end(&mut decoded)
+            .with_context(|| format!("failed to decompress {}", source))?;
+        decoded
+    } else {
+        bytes
+    };
+
+    let text = String::from_utf8(bytes).with_context(|| format!("{} is not valid UTF-8", source))?;
+    let text = text.strip_prefix('\u{feff}').unwrap_or(&text);
+    Ok(text.replace("\r\n", "\n").lines().map(String::from).collect())
+}
+
+pub fn read_input (input_path: impl AsRef<Path>) -> Result<Vec<String>> {
+    read_source(&InputSource::File(input_path.as_ref().to_path_buf()))
+}
+
+pub fn read_source (source: &InputSource) -> Result<Vec<String>> {
+    let mut bytes = Vec::new();
+    match source {
+        InputSource::Stdin => {
+            io::stdin().lock().read_to_end(&mut bytes).context("failed to read stdin")?;
+        }
+        InputSource::File(path) => {
+            let mut file = File::open(path).with_context(|| format!("failed to open {}", path.display()))?;
+            file.read_to_end(&mut bytes).with_context(|| format!("failed to read {}", path.display()))?;
+        }
+    }
+    decode(bytes, source)
+}
+
+// downloads the day's input into the cache, an input that is already cached is never refetched.
+// returns the cached path and whether it had to be downloaded
+pub async fn fetch_input (client: &AocClient, input_dir: impl AsRef<Path>, year: u32, day: u32) -> Result<(PathBuf, bool)> {
+    let path = input_path(input_dir, year, day);
+    if is_cached(&path) {
+        return Ok((path, false));
+    }
+
+    let body = client.fetch_input(year, day).await?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).with_context(|| format!("failed to create {}", parent.display()))?;
+    }
+
+    // write next to the target and rename so an interrupted download never looks cached
+    let partial = path.with_extension("txt.part");
+    fs::write(&partial, body).with_context(|| format!("failed to write {}", partial.display()))?;
+    fs::rename(&partial, &path).with_context(|| format!("failed to move input into {}", path.display()))?;
+    Ok((path, true))
+}
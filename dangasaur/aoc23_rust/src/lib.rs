@@ -1,6 +1,17 @@
+pub mod answers;
 pub mod aoc;
-pub mod day1;
-pub mod day2;
-pub mod day3;
-pub mod day4;
-pub mod day5;
\ No newline at end of file
+pub mod bench;
+pub mod cancel;
+pub mod client;
+pub mod error;
+pub mod explain;
+pub mod input;
+pub mod logging;
+pub mod registry;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod submit;
+pub mod watch;
+pub mod web;
+pub mod y2023;
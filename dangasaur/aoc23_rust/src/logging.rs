@@ -0,0 +1,32 @@
+use std::io::{self, IsTerminal};
+
+use tracing_subscriber::EnvFilter;
+
+pub const LOG_ENV: &str = "RUST_LOG";
+
+// -q only shows errors, no flag shows info, -v debug and -vv (or more) everything.
+// other crates stay at warn so a -vv run isn't drowned in http client noise
+pub fn filter (verbose: u8, quiet: bool) -> String {
+    let level = match (quiet, verbose) {
+        (true, _) => "error",
+        (false, 0) => "info",
+        (false, 1) => "debug",
+        (false, _) => "trace",
+    };
+    format!("warn,{}={}", env!("CARGO_CRATE_NAME"), level)
+}
+
+// logs go to stderr so stdout stays just the answers. RUST_LOG is used when set and no -v/-q was given
+pub fn init (verbose: u8, quiet: bool) {
+    let env_filter = match EnvFilter::try_from_env(LOG_ENV) {
+        Ok(env_filter) if verbose == 0 && !quiet => env_filter,
+        _ => EnvFilter::new(filter(verbose, quiet)),
+    };
+
+    tracing_subscriber::fmt()
+        .with_env_filter(env_filter)
+        .with_writer(io::stderr)
+        .with_ansi(io::stderr().is_terminal())
+        .without_time()
+        .init();
+}
@@ -1,73 +1,535 @@
-use anyhow::{Context, Result};
-use clap::Parser;
+use anyhow::{bail, Context, Result};
+use clap::{ArgAction, Parser, Subcommand};
 use std::{
-    fs::File,
-    io::{prelude::*, BufReader},
-    path::Path,
-    str::FromStr,
+    io::{self, IsTerminal},
+    path::{Path, PathBuf},
+    time::Duration,
 };
-use strum_macros::EnumString;
-
-use aoc23_rust::day1::Day1;
-use aoc23_rust::day2::Day2;
-use aoc23_rust::day3::Day3;
-use aoc23_rust::day4::Day4;
-use aoc23_rust::day5::Day5;
-use aoc23_rust::aoc::Solveable;
-
-#[derive(Debug, PartialEq, EnumString)]
-pub enum Day {
-    #[strum(serialize = "day1", serialize = "1")]
-    D1(Day1),
-
-    #[strum(serialize = "day2", serialize = "2")]
-    D2(Day2),
-
-    #[strum(serialize = "day3", serialize = "3")]
-    D3(Day3),
-    
-    #[strum(serialize = "day4", serialize = "4")]
-    D4(Day4),
-
-    #[strum(serialize = "day5", serialize = "5")]
-    D5(Day5),
+
+use tracing::{info, warn};
+
+use aoc23_rust::{answers, bench, cancel, client::AocClient, explain::{self, ExplainFormat}, input::{self, InputSource}, logging, registry::{self, DayRegistration}, report::{self, OutputFormat}, runner, scaffold, submit, watch, web, y2023::day5};
+
+// falls back to downloading conventional inputs that aren't cached yet, without a session it says why it can't
+async fn fetch_missing(input_dir: &Path, days: &[&DayRegistration]) {
+    let missing: Vec<&&DayRegistration> = days.iter()
+        .filter(|d| !input::is_cached(&input::input_path(input_dir, d.year, d.day)))
+        .collect();
+    if missing.is_empty() {
+        return;
+    }
+    let client = match AocClient::from_env() {
+        Ok(client) => client,
+        Err(e) => {
+            warn!("can't download the {} missing input(s): {:#}", missing.len(), e);
+            return;
+        }
+    };
+
+    for registration in missing {
+        match input::fetch_input(&client, input_dir, registration.year, registration.day).await {
+            Ok((path, _)) => info!("fetched {}", path.display()),
+            Err(e) => warn!("could not fetch input for day {}: {:#}", registration.day, e),
+        }
+    }
 }
 
-impl Solveable for Day {
-    fn solve (&self, lines: &Vec<String>) -> Result<aoc23_rust::aoc::Solution> {
-        match self {
-            Day::D1(inner) => inner.solve(lines),
-            Day::D2(inner) => inner.solve(lines),
-            Day::D3(inner) => inner.solve(lines),
-            Day::D4(inner) => inner.solve(lines),
-            Day::D5(inner) => inner.solve(lines),
+// an explicit path (or "-" for stdin) wins, then whatever was piped on stdin, and only with nothing piped
+// the cached input, downloaded if missing
+async fn load_input(input_file: Option<&Path>, input_dir: &Path, registration: &DayRegistration) -> Result<(InputSource, Vec<String>)> {
+    let source = match input_file {
+        Some(arg) => InputSource::from_arg(arg, input_dir),
+        None => {
+            if !io::stdin().is_terminal() {
+                let lines = input::read_source(&InputSource::Stdin)?;
+                if !lines.is_empty() {
+                    return Ok((InputSource::Stdin, lines));
+                }
+            }
+            return cached_input(input_dir, registration).await;
         }
+    };
+
+    let lines = input::read_source(&source)?;
+    Ok((source, lines))
+}
+
+// the conventional input under input_dir, downloaded if it isn't cached yet
+async fn cached_input(input_dir: &Path, registration: &DayRegistration) -> Result<(InputSource, Vec<String>)> {
+    fetch_missing(input_dir, &[registration]).await;
+    let source = InputSource::File(input::input_path(input_dir, registration.year, registration.day));
+    let lines = input::read_source(&source)?;
+    Ok((source, lines))
+}
+
+// json and csv own stdout, so the bookkeeping note moves to the log
+fn report_recorded(format: OutputFormat, recorded: usize, answers_file: &Path) {
+    if format == OutputFormat::Text {
+        println!("recorded {} answer(s) in {}", recorded, answers_file.display());
+    } else {
+        info!("recorded {} answer(s) in {}", recorded, answers_file.display());
     }
 }
 
-async fn read_input(input_path: impl AsRef<Path>) -> Result<Vec<String>> {
-    let file = File::open(input_path).expect("no such file");
-    let buf = BufReader::new(file);
-    buf.lines()
-        .map(|l| l.context("failed to parse line"))
-        .collect()
+// a timeout for each solver phase, ctrl-c interrupts whatever is running
+fn timeout(seconds: Option<f64>) -> Result<Option<Duration>> {
+    match seconds {
+        Some(seconds) if !(seconds.is_finite() && seconds > 0.0) => bail!("--timeout must be a positive number of seconds, got {}", seconds),
+        Some(seconds) => Ok(Some(Duration::from_secs_f64(seconds))),
+        None => Ok(None),
+    }
+}
+
+fn limits(seconds: Option<f64>) -> Result<runner::Limits> {
+    Ok(runner::Limits { timeout: timeout(seconds)?, interrupt: cancel::interrupt_on_ctrl_c() })
 }
 
-#[derive(Parser, Default, Debug)]
+#[derive(Parser, Debug)]
 struct Arguments {
-    day: String,
-    input_file: String,
+    /// Event year, defaults to the newest year with registered days
+    #[arg(long, global = true)]
+    year: Option<u32>,
+
+    /// More logging on stderr, -v for debug and -vv for trace. Without -v/-q RUST_LOG is honoured
+    #[arg(short, long, global = true, action = ArgAction::Count)]
+    verbose: u8,
+
+    /// Only log errors
+    #[arg(short, long, global = true, conflicts_with = "verbose")]
+    quiet: bool,
+
+    #[command(subcommand)]
+    command: Command,
 }
 
-#[tokio::main]
-async fn main() -> Result<()> {
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// List every registered day
+    List,
+    /// Solve a day, e.g. `run 2 input/2023/day2.txt`, one part with `run 2.1`, a range like `run 1..5`, or `run --all`
+    Run {
+        day: Option<String>,
+        /// Defaults to piped stdin, then input/<year>/dayN.txt, `-` reads stdin, only valid when a single day is selected
+        input_file: Option<PathBuf>,
+        /// Solve every registered day of the year and print a summary table
+        #[arg(long, conflicts_with = "day")]
+        all: bool,
+        /// Run the sample inputs embedded in each day and check them against the expected answers
+        #[arg(long, conflicts_with = "input_file")]
+        example: bool,
+        /// Lock the solved answers into the answers file
+        #[arg(long, conflicts_with = "example")]
+        record: bool,
+        /// With --record, replace answers that differ from the ones already recorded for the same input
+        #[arg(long, requires = "record")]
+        overwrite: bool,
+        /// Narrate how each answer was derived, `--explain` for text or `--explain=markdown`
+        #[arg(long, value_enum, num_args = 0..=1, require_equals = true, default_missing_value = "text", conflicts_with = "record")]
+        explain: Option<ExplainFormat>,
+        /// One record per day and part as json or csv, for diffing runs and dashboards
+        #[arg(long, value_enum, default_value = "text", conflicts_with_all = ["explain", "example"])]
+        format: OutputFormat,
+        /// Seconds parsing or a single part may run before it is given up on and reported as timed out
+        #[arg(long, conflicts_with_all = ["explain", "example"])]
+        timeout: Option<f64>,
+        /// Day 5 only, look numbers up in the almanac: `run 5 --query location=46 --to seed` lists every seed range reaching location 46
+        #[arg(long, requires = "to", conflicts_with_all = ["all", "example", "record", "explain", "format", "timeout"])]
+        query: Option<String>,
+        /// The category a --query is answered in
+        #[arg(long, requires = "query")]
+        to: Option<String>,
+        #[arg(long, default_value = answers::ANSWERS_FILE)]
+        answers_file: PathBuf,
+        #[arg(long, default_value = input::INPUT_DIR)]
+        input_dir: PathBuf,
+    },
+    /// Download puzzle inputs into the input cache, e.g. `fetch 5` or `fetch --all`
+    Fetch {
+        day: Option<String>,
+        #[arg(long, conflicts_with = "day")]
+        all: bool,
+        #[arg(long, default_value = input::INPUT_DIR)]
+        input_dir: PathBuf,
+    },
+    /// Solve one part and submit the answer, e.g. `submit --day 5 --part 1`
+    Submit {
+        /// The day, or day and part like `5.1`
+        #[arg(long)]
+        day: String,
+        /// Required unless the day names the part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Defaults to piped stdin, then input/<year>/dayN.txt, `-` reads stdin
+        input_file: Option<PathBuf>,
+        #[arg(long, default_value = answers::ANSWERS_FILE)]
+        answers_file: PathBuf,
+        #[arg(long, default_value = submit::GUESSES_FILE)]
+        guesses_file: PathBuf,
+        #[arg(long, default_value = input::INPUT_DIR)]
+        input_dir: PathBuf,
+    },
+    /// Re-run every day of the year and fail if any recorded answer drifted
+    Verify {
+        /// Seconds parsing or a single part may run before it is given up on and reported as timed out
+        #[arg(long)]
+        timeout: Option<f64>,
+        #[arg(long, default_value = answers::ANSWERS_FILE)]
+        answers_file: PathBuf,
+        #[arg(long, default_value = input::INPUT_DIR)]
+        input_dir: PathBuf,
+    },
+    /// Generate src/y<year>/dayN.rs from a template, declare it and create empty sample and input files
+    NewDay {
+        day: u32,
+        /// Puzzle title shown by `list`, defaults to "Day N"
+        #[arg(long)]
+        title: Option<String>,
+        #[arg(long, default_value = input::INPUT_DIR)]
+        input_dir: PathBuf,
+    },
+    /// Re-run a day whenever its input changes, dev builds also rebuild when the day's source changes
+    Watch {
+        /// The day, or day and part like `5.1`
+        #[arg(long)]
+        day: String,
+        /// Defaults to input/<year>/dayN.txt
+        input_file: Option<PathBuf>,
+        #[arg(long, default_value = input::INPUT_DIR)]
+        input_dir: PathBuf,
+    },
+    /// Serve a local web page for picking a day, uploading input and reading the explained answers
+    Serve {
+        #[arg(long, default_value = web::ADDRESS)]
+        address: String,
+        /// Served under /static/, an htmx.min.js here is used instead of the built-in htmx 1.9.3
+        #[arg(long, default_value = web::STATIC_DIR)]
+        static_dir: PathBuf,
+        /// Seconds parsing or a single part may run before the page reports it as timed out
+        #[arg(long, default_value_t = web::TIMEOUT_SECS)]
+        timeout: f64,
+    },
+    /// Time a day's parse, part 1 and part 2 over many iterations, `bench 3.2` only times part 2
+    Bench {
+        day: String,
+        /// Defaults to piped stdin, then input/<year>/dayN.txt, `-` reads stdin
+        input_file: Option<PathBuf>,
+        #[arg(long, default_value_t = 3)]
+        warmup: usize,
+        #[arg(long, default_value_t = 20)]
+        iterations: usize,
+        /// Store this run as the day's baseline
+        #[arg(long)]
+        save_baseline: bool,
+        #[arg(long, default_value = bench::BASELINE_FILE)]
+        baseline_file: PathBuf,
+        /// Percent slowdown of a median over the baseline that counts as a regression
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+        #[arg(long, default_value = input::INPUT_DIR)]
+        input_dir: PathBuf,
+    },
+}
+
+fn main() -> Result<()> {
     let args = Arguments::parse();
-    let solver = Day::from_str(&args.day).context("unknown day lol")?;
-    let lines = read_input(Path::new(&args.input_file)).await.context("failed to get lines")?;
+    logging::init(args.verbose, args.quiet);
+    let runtime = tokio::runtime::Runtime::new().context("failed to start the async runtime")?;
+    let result = runtime.block_on(run(args));
+    // a timed out solver that never polls its cancellation token still holds a blocking thread, don't wait for it
+    runtime.shutdown_background();
+    result
+}
+
+async fn run(args: Arguments) -> Result<()> {
+    let year = match args.year {
+        Some(year) => year,
+        None => registry::latest_year()?,
+    };
+
+    match args.command {
+        Command::List => {
+            for day in registry::days(year) {
+                println!("{} day {:>2}: {} (aliases: {})", day.year, day.day, day.title, day.aliases.join(", "));
+            }
+        }
+        Command::Run { day, input_file, all, example, record, overwrite, explain, format, timeout, query, to, answers_file, input_dir } => {
+            let spec = match (&day, all) {
+                (_, true) => "all".to_string(),
+                (Some(day), false) => day.clone(),
+                (None, false) => bail!("pick a day, a range like 1..5, or pass --all"),
+            };
+            let selected = registry::select(year, &spec)?;
+
+            if let (Some(query), Some(to)) = (&query, &to) {
+                let [registry::Selection { registration, .. }] = selected[..] else {
+                    bail!("--query needs a single day");
+                };
+                if (registration.year, registration.day) != (2023, 5) {
+                    bail!("--query only knows the 2023 day 5 almanac");
+                }
+                let (from, range) = day5::parse_query(query)?;
+                let to = day5::parse_category(to)?;
+                let (_, lines) = load_input(input_file.as_deref(), &input_dir, registration).await?;
+                let input = registration.parse(&lines).context("failed to parse the almanac")?;
+                let almanac = input.downcast_ref::<day5::Almanac>().context("day 5 did not parse into an almanac")?;
+                println!("{}", day5::render_query(from, &range, to, &almanac.query(from, range.clone(), to)?));
+                return Ok(());
+            }
 
-    let solution = solver.solve(&lines).expect("failed to solve");
+            if let Some(format) = explain {
+                if input_file.is_some() && selected.len() > 1 {
+                    bail!("an input file can only be given for a single day, inputs are read from {}", input_dir.display());
+                }
+                // examples only explain the parts they have an expected answer for
+                let mut inputs: Vec<(&DayRegistration, Vec<String>, Vec<u8>)> = Vec::new();
+                for selection in &selected {
+                    let registration = selection.registration;
+                    if example {
+                        inputs.extend(registration.solver.examples().iter().map(|e| {
+                            let parts = selection.parts.iter().copied().filter(|part| e.expected(*part).is_some()).collect();
+                            (registration, e.lines(), parts)
+                        }));
+                    } else {
+                        // piped text can only belong to a single day
+                        let (_, lines) = if selected.len() > 1 {
+                            cached_input(&input_dir, registration).await?
+                        } else {
+                            load_input(input_file.as_deref(), &input_dir, registration).await?
+                        };
+                        inputs.push((registration, lines, selection.parts.to_vec()));
+                    }
+                }
 
-    println!("part 1 answer: {}", solution.part_1);
-    println!("part 2 answer: {}", solution.part_2);
+                let rendered = inputs.iter()
+                    .map(|(registration, lines, parts)| Ok(explain::render(&explain::explain_day(registration, lines, parts)?, format)))
+                    .collect::<Result<Vec<_>>>()?;
+                println!("{}", rendered.join("\n\n"));
+                return Ok(());
+            }
+
+            if example {
+                let runs: Vec<_> = selected.iter()
+                    .map(|selection| (selection.registration, runner::run_examples(selection.registration.solver, selection.parts)))
+                    .collect();
+                println!("{}", runner::render_examples(&runs));
+
+                let failed = runs.iter().flat_map(|(_, r)| r)
+                    .filter(|r| matches!(r.status(), runner::Status::Wrong | runner::Status::Error))
+                    .count();
+                if failed > 0 {
+                    bail!("{} example(s) did not match the expected answer", failed);
+                }
+                return Ok(());
+            }
+
+            // anything beyond a single day gets the summary table and keeps going past failures
+            if all || selected.len() > 1 {
+                if input_file.is_some() {
+                    bail!("an input file can only be given for a single day, inputs are read from {}", input_dir.display());
+                }
+                let registrations: Vec<&DayRegistration> = selected.iter().map(|s| s.registration).collect();
+                fetch_missing(&input_dir, &registrations).await;
+                let limits = limits(timeout)?;
+                let mut runs: Vec<runner::DayRun> = Vec::new();
+                for selection in &selected {
+                    runs.push(runner::run_day_from_dir_limited(selection.registration, &input_dir, selection.parts, &limits).await);
+                }
+                println!("{}", report::render(&runs, format)?);
+
+                if record {
+                    let mut store = answers::AnswerStore::load(&answers_file)?;
+                    let mut recorded = 0;
+                    for run in &runs {
+                        let source = input::input_path(&input_dir, run.year, run.day);
+                        recorded += runner::record(&mut store, run, source.to_str(), overwrite)?;
+                    }
+                    store.save(&answers_file)?;
+                    report_recorded(format, recorded, &answers_file);
+                }
+                if limits.interrupt.is_cancelled() {
+                    bail!("interrupted");
+                }
+                // every day still ran, but a broken one has to show up in the exit code
+                let parts: Vec<&runner::PartRun> = runs.iter().flat_map(|run| &run.parts).collect();
+                let failed = parts.iter().filter(|part| part.status() == runner::Status::Error).count();
+                if failed > 0 {
+                    bail!("{} of {} part(s) failed", failed, parts.len());
+                }
+                return Ok(());
+            }
+
+            let registry::Selection { registration, parts } = selected[0];
+            let (source, lines) = load_input(input_file.as_deref(), &input_dir, registration).await?;
+            let limits = limits(timeout)?;
+            let run = runner::run_day_limited(registration, lines, parts, &limits).await;
+
+            // machine readable output keeps going past a failed parse so every part still gets a record
+            if format != OutputFormat::Text {
+                println!("{}", report::render(std::slice::from_ref(&run), format)?);
+                if record {
+                    let mut store = answers::AnswerStore::load(&answers_file)?;
+                    let recorded = runner::record(&mut store, &run, Some(&source.to_string()), overwrite)?;
+                    store.save(&answers_file)?;
+                    report_recorded(format, recorded, &answers_file);
+                }
+                if limits.interrupt.is_cancelled() {
+                    bail!("interrupted");
+                }
+                return Ok(());
+            }
+
+            if let Some(error) = &run.error {
+                bail!("failed to solve: {}", error);
+            }
+            for part in &run.parts {
+                match &part.error {
+                    Some(error) => println!("part {} answer: error: {}", part.part, error),
+                    None => println!("part {} answer: {}", part.part, part.answer),
+                }
+            }
+
+            if record {
+                let mut store = answers::AnswerStore::load(&answers_file)?;
+                let recorded = runner::record(&mut store, &run, Some(&source.to_string()), overwrite)?;
+                store.save(&answers_file)?;
+                report_recorded(format, recorded, &answers_file);
+            }
+            if limits.interrupt.is_cancelled() {
+                bail!("interrupted");
+            }
+        }
+        Command::Fetch { day, all, input_dir } => {
+            let spec = match (&day, all) {
+                (_, true) => "all".to_string(),
+                (Some(day), false) => day.clone(),
+                (None, false) => bail!("pick a day, a range like 1..5, or pass --all"),
+            };
+            let client = AocClient::from_env()?;
+            for selection in registry::select(year, &spec)? {
+                let (path, downloaded) = input::fetch_input(&client, &input_dir, year, selection.registration.day).await?;
+                if downloaded {
+                    println!("fetched {}", path.display());
+                } else {
+                    println!("already cached {}", path.display());
+                }
+            }
+        }
+        Command::Submit { day, part, input_file, answers_file, guesses_file, input_dir } => {
+            let (name, parts) = registry::split_part(&day)?;
+            let part = match (part, parts) {
+                (Some(part), _) if parts.len() == 1 && parts[0] != part => bail!("--day {} and --part {} disagree", day, part),
+                (Some(part), _) => part,
+                (None, [part]) => *part,
+                (None, _) => bail!("pick a part with --part or address it as {}.1", name),
+            };
+            let registration = registry::find(year, name)?;
+            let (source, lines) = load_input(input_file.as_deref(), &input_dir, registration).await?;
+
+            let parsed = registration.parse(&lines).context("failed to parse input")?;
+            let answer = registration.part(part, &parsed).context("failed to solve")?;
+            println!("part {} answer: {}", part, answer);
+
+            let client = AocClient::from_env()?;
+            let mut guesses = submit::GuessLog::load(&guesses_file)?;
+            let verdict = submit::submit(&client, &mut guesses, year, registration.day, part, &answer).await;
+            guesses.save(&guesses_file)?;
+            let verdict = verdict?;
+            println!("{}", verdict);
+
+            match verdict {
+                submit::Verdict::Correct => {
+                    // the site just accepted it, so it replaces whatever was recorded before
+                    let mut store = answers::AnswerStore::load(&answers_file)?;
+                    let input_id = answers::input_id(&lines);
+                    store.forget(year, registration.day, &input_id, part);
+                    store.record(year, registration.day, &input_id, Some(&source.to_string()), part, &answer)?;
+                    store.save(&answers_file)?;
+                    println!("recorded in {}", answers_file.display());
+                }
+                submit::Verdict::AlreadySolved => {}
+                _ => bail!("answer was not accepted"),
+            }
+        }
+        Command::Verify { timeout, answers_file, input_dir } => {
+            let store = answers::AnswerStore::load(&answers_file)?;
+            let recorded = store.recorded(year);
+            let recorded_days: Vec<&DayRegistration> = registry::days(year).into_iter()
+                .filter(|r| recorded.iter().any(|(day, _, _)| *day == r.day))
+                .collect();
+            for registration in registry::days(year) {
+                if !recorded_days.iter().any(|r| r.day == registration.day) {
+                    println!("skipped day {}: nothing recorded in {}", registration.day, answers_file.display());
+                }
+            }
+            fetch_missing(&input_dir, &recorded_days).await;
+
+            let limits = limits(timeout)?;
+            let checks = runner::verify_recorded(&store, year, &input_dir, &limits).await;
+            println!("{}", runner::render_verify(&checks));
+            if limits.interrupt.is_cancelled() {
+                bail!("interrupted, {} recorded answer(s) checked so far", checks.len());
+            }
+
+            let drifted = checks.iter().filter(|c| c.status() != runner::Status::Ok).count();
+            if drifted > 0 {
+                bail!("{} of {} recorded answer(s) no longer match", drifted, checks.len());
+            }
+            println!("all {} recorded answer(s) still match", checks.len());
+        }
+        Command::NewDay { day, title, input_dir } => {
+            let title = title.unwrap_or_else(|| format!("Day {}", day));
+            let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+            for path in scaffold::new_day(root, &input_dir, year, day, &title)? {
+                println!("wrote {}", path.display());
+            }
+            println!("rebuild to register day {}, then paste the sample into {}/{}/day{}.txt", day, scaffold::SAMPLES_DIR, year, day);
+        }
+        Command::Watch { day, input_file, input_dir } => {
+            let registry::Selection { registration, parts } = registry::find_part(year, &day)?;
+            let input_path = match input_file {
+                Some(arg) => match InputSource::from_arg(&arg, &input_dir) {
+                    InputSource::File(path) => path,
+                    InputSource::Stdin => bail!("stdin can't be watched, give the input a file"),
+                },
+                None => {
+                    fetch_missing(&input_dir, &[registration]).await;
+                    input::input_path(&input_dir, year, registration.day)
+                }
+            };
+            watch::watch(watch::WatchConfig { registration, parts, input_path }).await?;
+        }
+        Command::Serve { address, static_dir, timeout: seconds } => {
+            // ctrl-c stops the whole server, so only the timeout applies to solves
+            let limits = runner::Limits { timeout: timeout(Some(seconds))?, ..Default::default() };
+            let listener = tokio::net::TcpListener::bind(&address).await
+                .with_context(|| format!("failed to listen on {}", address))?;
+            println!("serving {} on http://{}", year, listener.local_addr()?);
+            web::serve(listener, web::ServeConfig { year, static_dir, limits }).await?;
+        }
+        Command::Bench { day, input_file, warmup, iterations, save_baseline, baseline_file, threshold, input_dir } => {
+            let registry::Selection { registration, parts } = registry::find_part(year, &day)?;
+            let (_, lines) = load_input(input_file.as_deref(), &input_dir, registration).await?;
+
+            let config = bench::BenchConfig { warmup, iterations };
+            let report = bench::bench_day(registration, &lines, parts, &config)?;
+            println!("{}", bench::render(&report));
+
+            let regressions = match bench::load_baselines(&baseline_file)?.get(&report.key()) {
+                Some(baseline) => bench::compare(&report, baseline, threshold),
+                None => Vec::new(),
+            };
+            for regression in &regressions {
+                println!("{}", bench::render_regression(regression));
+            }
+
+            if save_baseline {
+                bench::save_baseline(&baseline_file, &report)?;
+                println!("saved baseline to {}", baseline_file.display());
+            }
+            if !regressions.is_empty() {
+                bail!("{} phase(s) regressed more than {}% against {}", regressions.len(), threshold, baseline_file.display());
+            }
+        }
+    }
     Ok(())
 }
@@ -0,0 +1,200 @@
+use std::any::Any;
+
+use anyhow::{bail, Context, Result};
+use tracing::info_span;
+
+use crate::{aoc::{Answer, Example, Solveable}, error};
+
+pub type ParsedInput = Box<dyn Any + Send + Sync>;
+
+// object safe view of a Solveable so days with different Input types can live in one registry
+pub trait Solver: Sync {
+    fn parse (&self, lines: &[String]) -> Result<ParsedInput>;
+    fn part_1 (&self, input: &ParsedInput) -> Result<Answer>;
+    fn part_2 (&self, input: &ParsedInput) -> Result<Answer>;
+    fn examples (&self) -> &'static [Example];
+
+    fn part (&self, part: u8, input: &ParsedInput) -> Result<Answer> {
+        match part {
+            1 => self.part_1(input),
+            2 => self.part_2(input),
+            _ => bail!("there is no part {}", part),
+        }
+    }
+}
+
+fn downcast<T: 'static> (input: &ParsedInput) -> Result<&T> {
+    input.downcast_ref::<T>().context("parsed input handed to the wrong solver")
+}
+
+impl<T> Solver for T
+where
+    T: Solveable + Sync,
+    T::Input: Send + Sync + 'static,
+{
+    fn parse (&self, lines: &[String]) -> Result<ParsedInput> {
+        Ok(Box::new(Solveable::parse(self, lines)?))
+    }
+
+    fn part_1 (&self, input: &ParsedInput) -> Result<Answer> {
+        Solveable::part_1(self, downcast::<T::Input>(input)?)
+    }
+
+    fn part_2 (&self, input: &ParsedInput) -> Result<Answer> {
+        Solveable::part_2(self, downcast::<T::Input>(input)?)
+    }
+
+    fn examples (&self) -> &'static [Example] {
+        T::EXAMPLES
+    }
+}
+
+pub struct DayRegistration {
+    pub year: u32,
+    pub day: u32,
+    pub title: &'static str,
+    pub aliases: &'static [&'static str],
+    pub solver: &'static dyn Solver,
+}
+
+inventory::collect!(DayRegistration);
+
+impl DayRegistration {
+    // the solver calls below tag any AocError with this day and part
+
+    pub fn parse (&self, lines: &[String]) -> Result<ParsedInput> {
+        let _span = info_span!("parse", year = self.year, day = self.day).entered();
+        self.solver.parse(lines).map_err(|e| error::locate(e, self.year, self.day, None))
+    }
+
+    pub fn part (&self, part: u8, input: &ParsedInput) -> Result<Answer> {
+        let _span = info_span!("part", year = self.year, day = self.day, part).entered();
+        self.solver.part(part, input).map_err(|e| error::locate(e, self.year, self.day, Some(part)))
+    }
+
+    // every day answers to "N" and "dayN" on top of whatever aliases it declares
+    pub fn matches (&self, name: &str) -> bool {
+        let name = name.trim().to_lowercase();
+        name == self.day.to_string()
+            || name == format!("day{}", self.day)
+            || self.aliases.iter().any(|a| a.to_lowercase() == name)
+    }
+}
+
+/// Declares a day to the registry, e.g. `register_day!(2023, 1, "Trebuchet?!", ["trebuchet"], Day1 {});`
+/// Also generates an `examples` test in the calling module that checks the day's `EXAMPLES`.
+#[macro_export]
+macro_rules! register_day {
+    ($year:expr, $day:expr, $title:expr, [$($alias:expr),* $(,)?], $solver:expr) => {
+        inventory::submit! {
+            $crate::registry::DayRegistration {
+                year: $year,
+                day: $day,
+                title: $title,
+                aliases: &[$($alias),*],
+                solver: &$solver,
+            }
+        }
+
+        #[cfg(test)]
+        #[test]
+        fn examples () {
+            let runs = $crate::runner::run_examples(&$solver, &$crate::registry::PARTS);
+            assert!(!runs.is_empty(), "day {} has no examples", $day);
+            for run in runs {
+                assert!(run.status() != $crate::runner::Status::Wrong && run.status() != $crate::runner::Status::Error,
+                    "day {} example {} part {}: expected {}, got {}", $day, run.example, run.part, run.expected, run.answer);
+            }
+        }
+    };
+}
+
+fn all () -> impl Iterator<Item = &'static DayRegistration> {
+    inventory::iter::<DayRegistration>.into_iter()
+}
+
+pub fn years () -> Vec<u32> {
+    let mut years: Vec<u32> = all().map(|d| d.year).collect();
+    years.sort();
+    years.dedup();
+    years
+}
+
+pub fn latest_year () -> Result<u32> {
+    years().last().copied().context("no days registered")
+}
+
+pub fn days (year: u32) -> Vec<&'static DayRegistration> {
+    let mut days: Vec<&DayRegistration> = all().filter(|d| d.year == year).collect();
+    days.sort_by_key(|d| d.day);
+    days
+}
+
+pub fn find (year: u32, name: &str) -> Result<&'static DayRegistration> {
+    if !years().contains(&year) {
+        let available: Vec<String> = years().iter().map(|y| y.to_string()).collect();
+        bail!("no days registered for {}, available years are: {}", year, available.join(", "))
+    }
+
+    let registered = days(year);
+    match registered.iter().find(|d| d.matches(name)) {
+        Some(day) => Ok(day),
+        None => {
+            let available: Vec<String> = registered.iter().map(|d| d.day.to_string()).collect();
+            bail!("unknown day '{}' for {}, available days are: {} (try `list`)", name, year, available.join(", "))
+        }
+    }
+}
+
+pub const PARTS: [u8; 2] = [1, 2];
+
+// a day and the parts asked of it, "5.1" only runs part 1
+#[derive(Clone, Copy)]
+pub struct Selection {
+    pub registration: &'static DayRegistration,
+    pub parts: &'static [u8],
+}
+
+// splits a trailing ".1" / ".2" off a day name, without one both parts are selected
+pub fn split_part (spec: &str) -> Result<(&str, &'static [u8])> {
+    let spec = spec.trim();
+    match spec.rsplit_once('.') {
+        Some((day, part)) if !spec.contains("..") => match part.trim() {
+            "1" => Ok((day, &PARTS[..1])),
+            "2" => Ok((day, &PARTS[1..])),
+            _ => bail!("invalid part '{}' in '{}', parts are 1 and 2", part, spec),
+        },
+        _ => Ok((spec, &PARTS)),
+    }
+}
+
+// a single day with an optional part, e.g. "5", "day5.2" or "almanac.1"
+pub fn find_part (year: u32, spec: &str) -> Result<Selection> {
+    let (name, parts) = split_part(spec)?;
+    Ok(Selection { registration: find(year, name)?, parts })
+}
+
+// accepts "all", a single day or alias with an optional ".1" / ".2" part, or an inclusive range like "1..5" / "1..=5"
+pub fn select (year: u32, spec: &str) -> Result<Vec<Selection>> {
+    let spec = spec.trim();
+    let all_parts = |registration| Selection { registration, parts: &PARTS };
+    if spec.eq_ignore_ascii_case("all") {
+        return Ok(days(year).into_iter().map(all_parts).collect());
+    }
+
+    if let Some((start, end)) = spec.split_once("..") {
+        let end = end.trim_start_matches('=');
+        let start = start.trim().parse::<u32>().with_context(|| format!("invalid range start in '{}'", spec))?;
+        let end = end.trim().parse::<u32>().with_context(|| format!("invalid range end in '{}'", spec))?;
+        let selected: Vec<Selection> = days(year).into_iter()
+            .filter(|d| d.day >= start && d.day <= end)
+            .map(all_parts)
+            .collect();
+        if selected.is_empty() {
+            bail!("no days registered for {} in range {}", year, spec)
+        }
+        return Ok(selected);
+    }
+
+    Ok(vec![find_part(year, spec)?])
+}
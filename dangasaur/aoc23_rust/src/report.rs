@@ -0,0 +1,108 @@
+use anyhow::{Context, Result};
+use clap::ValueEnum;
+use serde::Serialize;
+
+use crate::{aoc::Answer, runner::{self, DayRun}};
+
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    Text,
+    Json,
+    Csv,
+}
+
+// one flat row per day and part so json and csv share a schema, the answer's type alongside it
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Record {
+    pub year: u32,
+    pub day: u32,
+    pub part: u8,
+    // json gets numbers as numbers, see Answer's Serialize
+    pub answer: Answer,
+    pub answer_type: &'static str,
+    pub elapsed_ns: u64,
+    pub parse_ns: u64,
+    pub input_hash: Option<String>,
+    pub version: &'static str,
+}
+
+// csv can't nest, so the same row with the answer as text, an error as its reason and unsolved left empty
+#[derive(Serialize)]
+struct CsvRecord<'a> {
+    year: u32,
+    day: u32,
+    part: u8,
+    answer: Option<String>,
+    answer_type: &'static str,
+    elapsed_ns: u64,
+    parse_ns: u64,
+    input_hash: Option<&'a str>,
+    version: &'static str,
+}
+
+impl<'a> From<&'a Record> for CsvRecord<'a> {
+    fn from(record: &'a Record) -> Self {
+        let answer = match &record.answer {
+            Answer::Unsolved => None,
+            Answer::Error(reason) => Some(reason.clone()),
+            answer => Some(answer.to_string()),
+        };
+        CsvRecord {
+            year: record.year,
+            day: record.day,
+            part: record.part,
+            answer,
+            answer_type: record.answer_type,
+            elapsed_ns: record.elapsed_ns,
+            parse_ns: record.parse_ns,
+            input_hash: record.input_hash.as_deref(),
+            version: record.version,
+        }
+    }
+}
+
+fn nanos (elapsed: std::time::Duration) -> u64 {
+    u64::try_from(elapsed.as_nanos()).unwrap_or(u64::MAX)
+}
+
+pub fn records (runs: &[DayRun]) -> Vec<Record> {
+    runs.iter()
+        .flat_map(|run| run.parts.iter().map(move |part| {
+            Record {
+                year: run.year,
+                day: run.day,
+                part: part.part,
+                answer: part.answer.clone(),
+                answer_type: part.answer.kind(),
+                elapsed_ns: nanos(part.elapsed),
+                parse_ns: nanos(run.parse_elapsed),
+                input_hash: run.input_id.clone(),
+                version: VERSION,
+            }
+        }))
+        .collect()
+}
+
+pub fn render_json (runs: &[DayRun]) -> Result<String> {
+    serde_json::to_string_pretty(&records(runs)).context("failed to serialize records")
+}
+
+pub fn render_csv (runs: &[DayRun]) -> Result<String> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    for record in &records(runs) {
+        writer.serialize(CsvRecord::from(record)).context("failed to write csv record")?;
+    }
+    let bytes = writer.into_inner().context("failed to flush csv")?;
+    String::from_utf8(bytes).context("csv output is not UTF-8")
+}
+
+// text is the summary table, single day runs print their own friendlier lines
+pub fn render (runs: &[DayRun], format: OutputFormat) -> Result<String> {
+    match format {
+        OutputFormat::Text => Ok(runner::render_table(runs)),
+        OutputFormat::Json => render_json(runs),
+        OutputFormat::Csv => render_csv(runs).map(|csv| csv.trim_end().to_string()),
+    }
+}
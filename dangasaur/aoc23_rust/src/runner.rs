@@ -0,0 +1,464 @@
+use std::{
+    fmt,
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use anyhow::{anyhow, bail, Result};
+use tokio_util::sync::CancellationToken;
+use tracing::warn;
+
+use crate::{
+    aoc::Answer,
+    answers::{self, AnswerStore, StoredAnswers},
+    cancel,
+    error,
+    input::{self, InputSource},
+    registry::{self, DayRegistration, Solver},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Ok,
+    Unsolved,
+    Wrong,
+    Error,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Ok => write!(f, "ok"),
+            Status::Unsolved => write!(f, "unsolved"),
+            Status::Wrong => write!(f, "wrong"),
+            Status::Error => write!(f, "error"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct PartRun {
+    pub part: u8,
+    pub answer: Answer,
+    pub elapsed: Duration,
+    // the full diagnostic behind an Answer::Error, the answer itself only carries one line for tables and records
+    pub error: Option<String>,
+}
+
+impl PartRun {
+    fn failed (part: u8, error: &anyhow::Error, elapsed: Duration) -> Self {
+        PartRun { part, answer: Answer::Error(error::summary(error)), elapsed, error: Some(format!("{:#}", error)) }
+    }
+
+    pub fn status (&self) -> Status {
+        match self.answer {
+            Answer::Unsolved => Status::Unsolved,
+            Answer::Error(_) => Status::Error,
+            _ => Status::Ok,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct DayRun {
+    pub year: u32,
+    pub day: u32,
+    pub title: &'static str,
+    pub input_id: Option<String>,
+    pub parse_elapsed: Duration,
+    pub parts: Vec<PartRun>,
+    // why none of the parts could run, e.g. a missing input or a failed parse
+    pub error: Option<String>,
+}
+
+impl DayRun {
+    fn failed (registration: &DayRegistration, parts: &[u8], input_id: Option<String>, reason: &str, parse_elapsed: Duration) -> Self {
+        let parts = parts.iter().copied()
+            .map(|part| PartRun { part, answer: Answer::Error(reason.to_string()), elapsed: Duration::ZERO, error: None })
+            .collect();
+        DayRun { year: registration.year, day: registration.day, title: registration.title, input_id, parse_elapsed, parts, error: Some(reason.to_string()) }
+    }
+
+    // the parts get the one-line summary, the day keeps the diagnostic with its snippet
+    fn failed_with (registration: &DayRegistration, parts: &[u8], input_id: Option<String>, error: &anyhow::Error, parse_elapsed: Duration) -> Self {
+        let mut run = Self::failed(registration, parts, input_id, &error::summary(error), parse_elapsed);
+        run.error = Some(format!("{:#}", error));
+        run
+    }
+
+    pub fn total_elapsed (&self) -> Duration {
+        self.parse_elapsed + self.parts.iter().map(|p| p.elapsed).sum::<Duration>()
+    }
+}
+
+// a panicking solver becomes an error for that day instead of taking the whole run down with it
+fn catch_panic<T> (f: impl FnOnce() -> Result<T>) -> Result<T> {
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(result) => result,
+        Err(payload) => {
+            let message = payload.downcast_ref::<&str>().map(|s| s.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown panic".to_string());
+            Err(anyhow!("panicked: {}", message))
+        }
+    }
+}
+
+pub fn timed<T> (f: impl FnOnce() -> Result<T>) -> (Result<T>, Duration) {
+    let start = Instant::now();
+    let result = catch_panic(f);
+    (result, start.elapsed())
+}
+
+// only the given parts are run, so a slow part 2 doesn't hold up iterating on part 1
+pub fn run_day (registration: &DayRegistration, lines: &[String], parts: &[u8]) -> DayRun {
+    let input_id = Some(answers::input_id(lines));
+    let (parsed, parse_elapsed) = timed(|| registration.parse(lines));
+    let input = match parsed {
+        Ok(input) => input,
+        Err(e) => return DayRun::failed_with(registration, parts, input_id, &e, parse_elapsed),
+    };
+
+    let parts = parts.iter().copied()
+        .map(|part| {
+            match timed(|| registration.part(part, &input)) {
+                (Ok(answer), elapsed) => PartRun { part, answer, elapsed, error: None },
+                (Err(e), elapsed) => PartRun::failed(part, &e, elapsed),
+            }
+        })
+        .collect();
+
+    DayRun { year: registration.year, day: registration.day, title: registration.title, input_id, parse_elapsed, parts, error: None }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Limits {
+    // how long parsing or any one part may take, None waits as long as it takes
+    pub timeout: Option<Duration>,
+    // cancelled on ctrl-c, see cancel::interrupt_on_ctrl_c
+    pub interrupt: CancellationToken,
+}
+
+#[derive(Debug)]
+pub enum Limited<T> {
+    Done(Result<T>),
+    TimedOut,
+    Interrupted,
+}
+
+// runs f on a blocking thread with its own cancellation token that the timeout or an interrupt cancels.
+// a solver that never polls cancel::check keeps its thread busy, but the caller stops waiting for it either way
+pub async fn limited<T: Send + 'static> (limits: &Limits, f: impl FnOnce() -> Result<T> + Send + 'static) -> (Limited<T>, Duration) {
+    let start = Instant::now();
+    let token = limits.interrupt.child_token();
+    let task = tokio::task::spawn_blocking({
+        let token = token.clone();
+        move || cancel::with_token(token, || timed(f))
+    });
+    let deadline = async {
+        match limits.timeout {
+            Some(timeout) => tokio::time::sleep(timeout).await,
+            None => std::future::pending().await,
+        }
+    };
+
+    // an interrupt also wakes the solver up, checked first so its "cancelled" error doesn't hide why
+    tokio::select! {
+        biased;
+        _ = limits.interrupt.cancelled() => (Limited::Interrupted, start.elapsed()),
+        _ = deadline => {
+            token.cancel();
+            (Limited::TimedOut, start.elapsed())
+        }
+        joined = task => match joined {
+            Ok((result, elapsed)) => (Limited::Done(result), elapsed),
+            Err(e) => (Limited::Done(Err(anyhow!("solver task failed: {}", e))), start.elapsed()),
+        },
+    }
+}
+
+// the phase's result, or why it was given up on, logged with which day and part it was and how long it had run
+fn finished<T> (registration: &DayRegistration, phase: &str, limited: Limited<T>, elapsed: Duration) -> Result<Result<T>, String> {
+    match limited {
+        Limited::Done(result) => Ok(result),
+        Limited::TimedOut => {
+            warn!("day {} {} timed out after {:.3?}", registration.day, phase, elapsed);
+            Err(format!("timed out after {:.3?}", elapsed))
+        }
+        Limited::Interrupted => {
+            warn!("interrupted day {} {} after {:.3?}", registration.day, phase, elapsed);
+            Err(format!("interrupted after {:.3?}", elapsed))
+        }
+    }
+}
+
+// run_day with every phase on a blocking thread under the limits, once interrupted the remaining parts are not started
+pub async fn run_day_limited (registration: &'static DayRegistration, lines: Vec<String>, parts: &[u8], limits: &Limits) -> DayRun {
+    let input_id = Some(answers::input_id(&lines));
+    if limits.interrupt.is_cancelled() {
+        return DayRun::failed(registration, parts, input_id, "not run, interrupted", Duration::ZERO);
+    }
+
+    let (parsed, parse_elapsed) = limited(limits, move || registration.parse(&lines)).await;
+    let input = match finished(registration, "parse", parsed, parse_elapsed) {
+        Ok(Ok(input)) => Arc::new(input),
+        Ok(Err(e)) => return DayRun::failed_with(registration, parts, input_id, &e, parse_elapsed),
+        Err(reason) => return DayRun::failed(registration, parts, input_id, &reason, parse_elapsed),
+    };
+
+    let mut runs = Vec::new();
+    for &part in parts {
+        if limits.interrupt.is_cancelled() {
+            runs.push(PartRun { part, answer: Answer::Error("not run, interrupted".to_string()), elapsed: Duration::ZERO, error: None });
+            continue;
+        }
+        let shared = Arc::clone(&input);
+        let (answer, elapsed) = limited(limits, move || registration.part(part, &shared)).await;
+        runs.push(match finished(registration, &format!("part {}", part), answer, elapsed) {
+            Ok(Ok(answer)) => PartRun { part, answer, elapsed, error: None },
+            Ok(Err(e)) => PartRun::failed(part, &e, elapsed),
+            Err(reason) => PartRun { part, answer: Answer::Error(reason), elapsed, error: None },
+        });
+    }
+
+    DayRun { year: registration.year, day: registration.day, title: registration.title, input_id, parse_elapsed, parts: runs, error: None }
+}
+
+pub async fn run_day_from_dir_limited (registration: &'static DayRegistration, input_dir: impl AsRef<Path>, parts: &[u8], limits: &Limits) -> DayRun {
+    let path = input::input_path(input_dir, registration.year, registration.day);
+    match input::read_input(&path) {
+        Ok(lines) => run_day_limited(registration, lines, parts, limits).await,
+        Err(e) => DayRun::failed_with(registration, parts, None, &e, Duration::ZERO),
+    }
+}
+
+// locks every solved part of the run into the store, returns how many parts were recorded.
+// Answers that differ from the recorded ones fail the whole run unless overwrite replaces them
+pub fn record (store: &mut AnswerStore, run: &DayRun, source: Option<&str>, overwrite: bool) -> Result<usize> {
+    let Some(input_id) = &run.input_id else { return Ok(0) };
+    let mut recorded = 0;
+    let mut drifted = Vec::new();
+    for part in &run.parts {
+        if overwrite && part.answer.is_solved() {
+            store.forget(run.year, run.day, input_id, part.part);
+        }
+        match store.record(run.year, run.day, input_id, source, part.part, &part.answer) {
+            Ok(stored) => recorded += usize::from(stored),
+            Err(e) => drifted.push(e.to_string()),
+        }
+    }
+    if !drifted.is_empty() {
+        bail!("{}, pass --overwrite to record the new answer", drifted.join("; "));
+    }
+    Ok(recorded)
+}
+
+pub(crate) fn render_rows<const N: usize> (header: [&str; N], rows: &[[String; N]]) -> Vec<String> {
+    let mut widths = header.map(|h| h.len());
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+    }
+
+    let format_row = |cells: &[String]| {
+        cells.iter().zip(widths)
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect::<Vec<_>>()
+            .join(" | ")
+            .trim_end()
+            .to_string()
+    };
+
+    let mut lines = vec![
+        format_row(&header.map(String::from)),
+        widths.map(|w| "-".repeat(w)).join("-|-"),
+    ];
+    lines.extend(rows.iter().map(|row| format_row(row)));
+    lines
+}
+
+pub fn render_table (runs: &[DayRun]) -> String {
+    let mut rows: Vec<[String; 5]> = Vec::new();
+    for run in runs {
+        for part in &run.parts {
+            rows.push([
+                run.day.to_string(),
+                part.part.to_string(),
+                part.answer.to_string(),
+                part.status().to_string(),
+                format!("{:.3?}", part.elapsed),
+            ]);
+        }
+    }
+    let mut lines = render_rows(["day", "part", "answer", "status", "time"], &rows);
+
+    let total: Duration = runs.iter().map(|r| r.total_elapsed()).sum();
+    let solved = runs.iter().flat_map(|r| &r.parts).filter(|p| p.status() == Status::Ok).count();
+    let attempted = runs.iter().map(|r| r.parts.len()).sum::<usize>();
+    lines.push(format!("{}/{} parts solved in {:.3?} (including parsing)", solved, attempted, total));
+    lines.join("\n")
+}
+
+#[derive(Debug)]
+pub struct ExampleRun {
+    pub example: usize,
+    pub part: u8,
+    pub expected: Answer,
+    pub answer: Answer,
+    pub elapsed: Duration,
+}
+
+impl ExampleRun {
+    pub fn status (&self) -> Status {
+        match &self.answer {
+            Answer::Unsolved => Status::Unsolved,
+            Answer::Error(_) => Status::Error,
+            answer if *answer == self.expected => Status::Ok,
+            _ => Status::Wrong,
+        }
+    }
+}
+
+// runs every embedded example against the given parts it has an expected answer for
+pub fn run_examples (solver: &dyn Solver, parts: &[u8]) -> Vec<ExampleRun> {
+    let mut runs = Vec::new();
+    for (index, example) in solver.examples().iter().enumerate() {
+        let lines = example.lines();
+        let parsed = catch_panic(|| solver.parse(&lines));
+
+        for &part in parts {
+            let Some(expected) = example.expected(part) else { continue };
+            let (answer, elapsed) = match &parsed {
+                Ok(input) => timed(|| solver.part(part, input)),
+                Err(e) => (Err(anyhow!("{}", error::summary(e))), Duration::ZERO),
+            };
+            let answer = answer.unwrap_or_else(|e| Answer::Error(error::summary(&e)));
+            runs.push(ExampleRun { example: index + 1, part, expected, answer, elapsed });
+        }
+    }
+    runs
+}
+
+pub fn render_examples (runs: &[(&DayRegistration, Vec<ExampleRun>)]) -> String {
+    let mut rows: Vec<[String; 7]> = Vec::new();
+    for (registration, example_runs) in runs {
+        for run in example_runs {
+            rows.push([
+                registration.day.to_string(),
+                run.example.to_string(),
+                run.part.to_string(),
+                run.expected.to_string(),
+                run.answer.to_string(),
+                run.status().to_string(),
+                format!("{:.3?}", run.elapsed),
+            ]);
+        }
+    }
+    render_rows(["day", "example", "part", "expected", "answer", "status", "time"], &rows).join("\n")
+}
+
+#[derive(Debug)]
+pub struct VerifyRun {
+    pub day: u32,
+    pub part: u8,
+    pub expected: Answer,
+    pub answer: Answer,
+    pub elapsed: Duration,
+}
+
+impl VerifyRun {
+    // once an answer is recorded anything else, unsolved included, counts as drift
+    pub fn status (&self) -> Status {
+        match &self.answer {
+            Answer::Error(_) => Status::Error,
+            answer if *answer == self.expected => Status::Ok,
+            _ => Status::Wrong,
+        }
+    }
+}
+
+// compares a run against the recorded answers for the same input, parts without a recorded answer are skipped
+pub fn verify (store: &AnswerStore, run: &DayRun) -> Vec<VerifyRun> {
+    let Some(input_id) = &run.input_id else { return Vec::new() };
+    run.parts.iter()
+        .filter_map(|part| {
+            let expected = store.expected(run.year, run.day, input_id, part.part)?;
+            Some(VerifyRun { day: run.day, part: part.part, expected: expected.clone(), answer: part.answer.clone(), elapsed: part.elapsed })
+        })
+        .collect()
+}
+
+// the recorded source first, unless it was stdin, then the conventional path under input_dir
+fn recorded_inputs (stored: &StoredAnswers, input_dir: &Path, year: u32, day: u32) -> Vec<PathBuf> {
+    let mut paths: Vec<PathBuf> = stored.source.iter()
+        .filter(|source| **source != InputSource::Stdin.to_string())
+        .map(PathBuf::from)
+        .collect();
+    let conventional = input::input_path(input_dir, year, day);
+    if !paths.contains(&conventional) {
+        paths.push(conventional);
+    }
+    paths
+}
+
+// the first candidate that still hashes to the recorded input, or why none of them do
+fn find_recorded_input (paths: &[PathBuf], input_id: &str) -> Result<Vec<String>, String> {
+    let mut problems = Vec::new();
+    for path in paths {
+        match input::read_input(path) {
+            Ok(lines) if answers::input_id(&lines) == input_id => return Ok(lines),
+            Ok(_) => problems.push(format!("{} changed", path.display())),
+            Err(_) if !path.exists() => problems.push(format!("{} missing", path.display())),
+            Err(e) => problems.push(format!("{} unreadable ({})", path.display(), e.root_cause())),
+        }
+    }
+    Err(format!("no input matches recorded hash {}: {}", input_id, problems.join(", ")))
+}
+
+// re-solves every answer recorded for the year from the input it was recorded from. a recorded day that is no
+// longer registered or whose input is gone or changed fails each of its recorded parts
+pub async fn verify_recorded (store: &AnswerStore, year: u32, input_dir: &Path, limits: &Limits) -> Vec<VerifyRun> {
+    let mut checks = Vec::new();
+    for (day, input_id, stored) in store.recorded(year) {
+        if limits.interrupt.is_cancelled() {
+            break;
+        }
+        let parts = stored.parts();
+        let failed = |reason: String| parts.iter()
+            .map(|&part| VerifyRun {
+                day,
+                part,
+                expected: stored.get(part).cloned().unwrap_or(Answer::Unsolved),
+                answer: Answer::Error(reason.clone()),
+                elapsed: Duration::ZERO,
+            })
+            .collect::<Vec<_>>();
+
+        let Some(registration) = registry::days(year).into_iter().find(|r| r.day == day) else {
+            checks.extend(failed(format!("day {} is no longer registered", day)));
+            continue;
+        };
+        match find_recorded_input(&recorded_inputs(stored, input_dir, year, day), input_id) {
+            Ok(lines) => checks.extend(verify(store, &run_day_limited(registration, lines, &parts, limits).await)),
+            Err(reason) => checks.extend(failed(reason)),
+        }
+    }
+    checks
+}
+
+pub fn render_verify (runs: &[VerifyRun]) -> String {
+    let rows: Vec<[String; 6]> = runs.iter()
+        .map(|run| [
+            run.day.to_string(),
+            run.part.to_string(),
+            run.expected.to_string(),
+            run.answer.to_string(),
+            run.status().to_string(),
+            format!("{:.3?}", run.elapsed),
+        ])
+        .collect();
+    render_rows(["day", "part", "expected", "answer", "status", "time"], &rows).join("\n")
+}
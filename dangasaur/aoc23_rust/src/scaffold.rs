@@ -0,0 +1,131 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Context, Result};
+
+use crate::input;
+
+pub const SAMPLES_DIR: &str = "samples";
+
+// __YEAR__, __DAY__ and __TITLE__ are filled in by new_day
+const TEMPLATE: &str = r#"use anyhow::Result;
+
+use crate::aoc::{Answer, Example, Solveable};
+use crate::register_day;
+
+// paste the puzzle's sample input here
+const EXAMPLE: &str = include_str!("../../samples/__YEAR__/day__DAY__.txt");
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Day__DAY__ {}
+register_day!(__YEAR__, __DAY__, __TITLE__, [], Day__DAY__ {});
+
+impl Solveable for Day__DAY__ {
+    type Input = Vec<String>;
+
+    // fill in the sample answers from the puzzle text, the generated test passes while a part is unsolved
+    const EXAMPLES: &'static [Example] = &[
+        Example { input: EXAMPLE, part_1: Some("0"), part_2: Some("0") },
+    ];
+
+    fn parse (&self, lines: &[String]) -> Result<Self::Input> {
+        Ok(lines.to_vec())
+    }
+
+    fn part_1 (&self, _input: &Self::Input) -> Result<Answer> {
+        Ok(Answer::Unsolved)
+    }
+
+    fn part_2 (&self, _input: &Self::Input) -> Result<Answer> {
+        Ok(Answer::Unsolved)
+    }
+}
+"#;
+
+pub fn render_template (year: u32, day: u32, title: &str) -> String {
+    TEMPLATE
+        .replace("__YEAR__", &year.to_string())
+        .replace("__DAY__", &day.to_string())
+        .replace("__TITLE__", &format!("{:?}", title))
+}
+
+// adds `pub mod <name>;` to a module file keeping the yNNNN / dayN declarations in numeric order,
+// returns false when it is already declared
+fn declare_module (path: &Path, name: &str, prefix: &str) -> Result<bool> {
+    let contents = if path.exists() {
+        fs::read_to_string(path).with_context(|| format!("failed to read {}", path.display()))?
+    } else {
+        String::new()
+    };
+    let declaration = format!("pub mod {};", name);
+    if contents.lines().any(|l| l.trim() == declaration) {
+        return Ok(false);
+    }
+
+    let number = |line: &str| {
+        line.trim().strip_prefix("pub mod ")?.strip_suffix(';')?.strip_prefix(prefix)?.parse::<u32>().ok()
+    };
+    let ours = number(&declaration);
+    let mut lines: Vec<String> = contents.lines().map(String::from).collect();
+    // after the last numbered sibling that sorts before us, or at the end
+    let position = lines.iter()
+        .rposition(|l| matches!((number(l), ours), (Some(theirs), Some(ours)) if theirs < ours))
+        .map(|i| i + 1)
+        .unwrap_or(lines.len());
+    lines.insert(position, declaration);
+
+    fs::write(path, lines.join("\n") + "\n").with_context(|| format!("failed to write {}", path.display()))?;
+    Ok(true)
+}
+
+fn create_placeholder (path: &Path, created: &mut Vec<PathBuf>) -> Result<()> {
+    if path.exists() {
+        return Ok(());
+    }
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).with_context(|| format!("failed to create {}", parent.display()))?;
+    }
+    fs::write(path, "").with_context(|| format!("failed to write {}", path.display()))?;
+    created.push(path.to_path_buf());
+    Ok(())
+}
+
+// generates src/y<year>/day<N>.rs under root, declares it in the year's mod.rs (and lib.rs for a new year),
+// and creates empty sample and input files. returns every file created or edited
+pub fn new_day (root: &Path, input_dir: &Path, year: u32, day: u32, title: &str) -> Result<Vec<PathBuf>> {
+    if !(1..=25).contains(&day) {
+        bail!("day must be between 1 and 25, got {}", day);
+    }
+
+    let src = root.join("src");
+    let year_dir = src.join(format!("y{}", year));
+    let module = year_dir.join(format!("day{}.rs", day));
+    if module.exists() {
+        bail!("{} already exists, refusing to overwrite it", module.display());
+    }
+    let year_mod = year_dir.join("mod.rs");
+    if year_mod.exists() && fs::read_to_string(&year_mod)?.lines().any(|l| l.trim() == format!("pub mod day{};", day)) {
+        bail!("day{} is already declared in {}", day, year_mod.display());
+    }
+
+    let mut touched = Vec::new();
+    fs::create_dir_all(&year_dir).with_context(|| format!("failed to create {}", year_dir.display()))?;
+    fs::write(&module, render_template(year, day, title)).with_context(|| format!("failed to write {}", module.display()))?;
+    touched.push(module);
+
+    let new_year = !year_mod.exists();
+    declare_module(&year_mod, &format!("day{}", day), "day")?;
+    touched.push(year_mod);
+    if new_year {
+        let lib = src.join("lib.rs");
+        if declare_module(&lib, &format!("y{}", year), "y")? {
+            touched.push(lib);
+        }
+    }
+
+    create_placeholder(&root.join(SAMPLES_DIR).join(year.to_string()).join(format!("day{}.txt", day)), &mut touched)?;
+    create_placeholder(&input::input_path(input_dir, year, day), &mut touched)?;
+    Ok(touched)
+}
@@ -0,0 +1,183 @@
+use std::{
+    collections::BTreeMap,
+    fmt, fs,
+    path::Path,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{bail, Context, Result};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::{aoc::Answer, client::AocClient};
+
+pub const GUESSES_FILE: &str = "guesses.json";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    RateLimited { wait_secs: u64 },
+    AlreadySolved,
+    Unknown(String),
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "wrong, too high"),
+            Verdict::TooLow => write!(f, "wrong, too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::RateLimited { wait_secs } => write!(f, "rate limited, wait {}s", wait_secs),
+            Verdict::AlreadySolved => write!(f, "already solved (or the part isn't unlocked)"),
+            Verdict::Unknown(text) => write!(f, "unrecognised response: {}", text),
+        }
+    }
+}
+
+// the verdict lives in the page's <article>, tags stripped
+fn article_text (html: &str) -> Result<String> {
+    let article = Regex::new(r"(?s)<article[^>]*>(.*?)</article>").context("invalid article pattern")?;
+    let tags = Regex::new(r"<[^>]+>").context("invalid tag pattern")?;
+    let body = article.captures(html).map(|c| c[1].to_string()).unwrap_or_else(|| html.to_string());
+    Ok(tags.replace_all(&body, "").split_whitespace().collect::<Vec<_>>().join(" "))
+}
+
+// "You have 1m 23s left to wait" after answering too fast, "please wait 5 minutes" after a wrong answer
+pub fn parse_wait (html: &str) -> Result<Option<Duration>> {
+    let text = article_text(html)?;
+    let left_to_wait = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").context("invalid left to wait pattern")?;
+    if let Some(c) = left_to_wait.captures(&text) {
+        let minutes: u64 = c.get(1).map_or(0, |m| m.as_str().parse().unwrap_or(0));
+        let seconds: u64 = c[2].parse().unwrap_or(0);
+        return Ok(Some(Duration::from_secs(minutes * 60 + seconds)));
+    }
+
+    let wait_minutes = Regex::new(r"(?i)wait (one|\d+) minutes?").context("invalid wait minutes pattern")?;
+    Ok(wait_minutes.captures(&text).map(|c| {
+        let minutes: u64 = if &c[1] == "one" { 1 } else { c[1].parse().unwrap_or(1) };
+        Duration::from_secs(minutes * 60)
+    }))
+}
+
+pub fn parse_response (html: &str) -> Result<Verdict> {
+    let text = article_text(html)?;
+    let verdict = if text.contains("That's the right answer") {
+        Verdict::Correct
+    } else if text.contains("You gave an answer too recently") {
+        Verdict::RateLimited { wait_secs: parse_wait(html)?.map_or(60, |w| w.as_secs()) }
+    } else if text.contains("That's not the right answer") {
+        if text.contains("too high") {
+            Verdict::TooHigh
+        } else if text.contains("too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        }
+    } else if text.contains("You don't seem to be solving the right level") {
+        Verdict::AlreadySolved
+    } else {
+        Verdict::Unknown(text)
+    };
+    Ok(verdict)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Guess {
+    pub answer: Answer,
+    pub verdict: Verdict,
+}
+
+// wrong guesses are per account rather than per input, so they're keyed year -> day -> part
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct GuessLog {
+    #[serde(default)]
+    pub not_before: u64,
+    #[serde(default)]
+    pub guesses: BTreeMap<u32, BTreeMap<u32, BTreeMap<u8, Vec<Guess>>>>,
+}
+
+fn now_secs () -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs())
+}
+
+impl GuessLog {
+    pub fn load (path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Ok(GuessLog::default());
+        }
+        let contents = fs::read_to_string(path).with_context(|| format!("failed to read guesses {}", path.display()))?;
+        serde_json::from_str(&contents).with_context(|| format!("failed to parse guesses {}", path.display()))
+    }
+
+    pub fn save (&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        let json = serde_json::to_string_pretty(self)?;
+        fs::write(path, json).with_context(|| format!("failed to write guesses {}", path.display()))
+    }
+
+    pub fn guesses (&self, year: u32, day: u32, part: u8) -> &[Guess] {
+        self.guesses.get(&year).and_then(|d| d.get(&day)).and_then(|p| p.get(&part)).map_or(&[], |g| g.as_slice())
+    }
+
+    // refuses answers that were already rejected or fall outside a too high / too low bound
+    pub fn check (&self, year: u32, day: u32, part: u8, answer: &Answer) -> Result<()> {
+        for guess in self.guesses(year, day, part) {
+            if guess.verdict == Verdict::Correct {
+                bail!("part {} was already accepted with {}", part, guess.answer);
+            }
+            if guess.answer == *answer && matches!(guess.verdict, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong) {
+                bail!("{} was already rejected ({})", answer, guess.verdict);
+            }
+            if let (Some(guessed), Some(candidate)) = (guess.answer.as_i128(), answer.as_i128()) {
+                if guess.verdict == Verdict::TooHigh && candidate >= guessed {
+                    bail!("{} can't be right, {} was already too high", answer, guess.answer);
+                }
+                if guess.verdict == Verdict::TooLow && candidate <= guessed {
+                    bail!("{} can't be right, {} was already too low", answer, guess.answer);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn wait_remaining (&self) -> Option<Duration> {
+        let now = now_secs();
+        (self.not_before > now).then(|| Duration::from_secs(self.not_before - now))
+    }
+
+    pub fn record (&mut self, year: u32, day: u32, part: u8, answer: &Answer, verdict: &Verdict, wait: Option<Duration>) {
+        if let Some(wait) = wait {
+            self.not_before = self.not_before.max(now_secs() + wait.as_secs());
+        }
+        // a rate limited or unparseable submission says nothing about the answer
+        if matches!(verdict, Verdict::RateLimited { .. } | Verdict::Unknown(_)) {
+            return;
+        }
+        self.guesses.entry(year).or_default()
+            .entry(day).or_default()
+            .entry(part).or_default()
+            .push(Guess { answer: answer.clone(), verdict: verdict.clone() });
+    }
+}
+
+// checks the guess log and the throttle before posting, then remembers what the site said
+pub async fn submit (client: &AocClient, log: &mut GuessLog, year: u32, day: u32, part: u8, answer: &Answer) -> Result<Verdict> {
+    if !answer.is_solved() {
+        bail!("refusing to submit day {} part {}: {}", day, part, answer);
+    }
+    if let Some(wait) = log.wait_remaining() {
+        bail!("still throttled, wait another {}s before submitting", wait.as_secs());
+    }
+    log.check(year, day, part, answer)?;
+
+    let html = client.submit_answer(year, day, part, answer).await?;
+    let verdict = parse_response(&html)?;
+    log.record(year, day, part, answer, &verdict, parse_wait(&html)?);
+    Ok(verdict)
+}
@@ -0,0 +1,198 @@
+use std::{
+    collections::BTreeMap,
+    env,
+    path::{Path, PathBuf},
+    process::Command,
+    time::Duration,
+};
+
+use anyhow::{bail, Context, Result};
+use notify::{EventKind, RecursiveMode, Watcher};
+use tokio::sync::mpsc;
+use tracing::{info, warn};
+
+use crate::{
+    aoc::Answer,
+    input,
+    registry::DayRegistration,
+    runner::{self, DayRun, ExampleRun, Status},
+};
+
+// answers from before a rebuild, handed to the freshly built binary so it can still diff against them
+pub const PREVIOUS_ENV: &str = "AOC_WATCH_PREVIOUS";
+
+// editors tend to write a file in several steps, events this close together count as one change
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+pub struct WatchConfig {
+    pub registration: &'static DayRegistration,
+    pub parts: &'static [u8],
+    pub input_path: PathBuf,
+}
+
+pub type Answers = BTreeMap<u8, Answer>;
+
+// the day's module in this checkout, only watched by dev builds that have cargo and the sources around
+pub fn source_path (registration: &DayRegistration) -> Option<PathBuf> {
+    if !cfg!(debug_assertions) {
+        return None;
+    }
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("src")
+        .join(format!("y{}", registration.year))
+        .join(format!("day{}.rs", registration.day));
+    path.exists().then_some(path)
+}
+
+fn answers (run: &DayRun) -> Answers {
+    run.parts.iter().map(|p| (p.part, p.answer.clone())).collect()
+}
+
+fn example_cell (runs: &[&ExampleRun]) -> String {
+    if runs.is_empty() {
+        return "-".to_string();
+    }
+    runs.iter()
+        .map(|run| match run.status() {
+            Status::Wrong => format!("{} wrong, expected {}", run.answer, run.expected),
+            status => format!("{} {}", run.answer, status),
+        })
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+// answers next to what they were last time and what the examples give, so one glance says if a change helped
+pub fn render (run: &DayRun, previous: Option<&Answers>, examples: &[ExampleRun]) -> String {
+    let rows: Vec<[String; 5]> = run.parts.iter()
+        .map(|part| {
+            let was = match previous.and_then(|p| p.get(&part.part)) {
+                None => "-".to_string(),
+                Some(before) if *before == part.answer => "unchanged".to_string(),
+                Some(before) => format!("was {}", before),
+            };
+            let part_examples: Vec<&ExampleRun> = examples.iter().filter(|e| e.part == part.part).collect();
+            [
+                part.part.to_string(),
+                part.answer.to_string(),
+                was,
+                example_cell(&part_examples),
+                format!("{:.3?}", part.elapsed),
+            ]
+        })
+        .collect();
+    runner::render_rows(["part", "answer", "previous", "examples", "time"], &rows).join("\n")
+}
+
+fn run_once (config: &WatchConfig, previous: Option<&Answers>) -> Answers {
+    let run = match input::read_input(&config.input_path) {
+        Ok(lines) => runner::run_day(config.registration, &lines, config.parts),
+        Err(e) => {
+            warn!("{:#}", e);
+            return previous.cloned().unwrap_or_default();
+        }
+    };
+    let examples = runner::run_examples(config.registration.solver, config.parts);
+    println!("{}", render(&run, previous, &examples));
+    answers(&run)
+}
+
+fn rebuild () -> Result<bool> {
+    let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let status = Command::new(cargo)
+        .args(["build", "--quiet"])
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .status()
+        .context("failed to run cargo build")?;
+    Ok(status.success())
+}
+
+// replaces this process with the freshly built binary and the same arguments, it picks up where we left off
+fn restart (exe: &Path, previous: &Answers) -> Result<()> {
+    let mut command = Command::new(exe);
+    command.args(env::args_os().skip(1)).env(PREVIOUS_ENV, serde_json::to_string(previous)?);
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        Err(command.exec()).with_context(|| format!("failed to restart {}", exe.display()))
+    }
+    #[cfg(not(unix))]
+    {
+        let status = command.status().with_context(|| format!("failed to restart {}", exe.display()))?;
+        std::process::exit(status.code().unwrap_or(1))
+    }
+}
+
+fn previous_from_env () -> Option<Answers> {
+    let previous = env::var(PREVIOUS_ENV).ok()?;
+    serde_json::from_str(&previous).ok()
+}
+
+// the path notify will report for a file inside a watched directory
+fn watched_target (path: &Path) -> Result<(PathBuf, PathBuf)> {
+    let dir = path.parent().filter(|d| !d.as_os_str().is_empty()).unwrap_or(Path::new("."));
+    let dir = dir.canonicalize().with_context(|| format!("failed to resolve {}", dir.display()))?;
+    let name = path.file_name().with_context(|| format!("{} is not a file", path.display()))?;
+    Ok((dir.clone(), dir.join(name)))
+}
+
+pub async fn watch (config: WatchConfig) -> Result<()> {
+    if !config.input_path.exists() {
+        bail!("{} does not exist, nothing to watch", config.input_path.display());
+    }
+    // cargo replaces the binary on rebuild, so remember where it lives before that happens
+    let exe = env::current_exe().context("failed to locate the running binary")?;
+    let source = source_path(config.registration);
+
+    let (sender, mut receiver) = mpsc::unbounded_channel();
+    let mut watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+        if let Ok(event) = event {
+            let _ = sender.send(event);
+        }
+    }).context("failed to start file watcher")?;
+
+    let (input_dir, input_target) = watched_target(&config.input_path)?;
+    watcher.watch(&input_dir, RecursiveMode::NonRecursive).context("failed to watch the input")?;
+    let source_target = match &source {
+        Some(source) => {
+            let (source_dir, source_target) = watched_target(source)?;
+            watcher.watch(&source_dir, RecursiveMode::NonRecursive).context("failed to watch the source")?;
+            Some(source_target)
+        }
+        None => None,
+    };
+
+    let day = config.registration.day;
+    match &source {
+        Some(source) => info!("watching {} and {}, ctrl-c to stop", config.input_path.display(), source.display()),
+        None => info!("watching {}, ctrl-c to stop (only dev builds next to their sources rebuild on changes)", config.input_path.display()),
+    }
+
+    let previous = previous_from_env();
+    println!("day {}: {}", day, config.registration.title);
+    let mut last = run_once(&config, previous.as_ref());
+
+    while let Some(event) = receiver.recv().await {
+        let mut events = vec![event];
+        while let Ok(Some(event)) = tokio::time::timeout(DEBOUNCE, receiver.recv()).await {
+            events.push(event);
+        }
+
+        let changed = |target: &Path| events.iter()
+            .filter(|e| !matches!(e.kind, EventKind::Access(_)))
+            .any(|e| e.paths.iter().any(|p| p == target));
+
+        if source_target.as_deref().is_some_and(changed) {
+            println!("\nsource changed, rebuilding day {}", day);
+            match rebuild() {
+                Ok(true) => restart(&exe, &last)?,
+                Ok(false) => println!("build failed, waiting for the next change"),
+                Err(e) => warn!("{:#}", e),
+            }
+        } else if changed(&input_target) {
+            println!("\ninput changed, re-running day {}", day);
+            last = run_once(&config, Some(&last));
+        }
+    }
+    Ok(())
+}
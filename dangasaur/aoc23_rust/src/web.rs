@@ -0,0 +1,269 @@
+use std::{
+    fmt::Write,
+    path::PathBuf,
+    sync::Arc,
+    time::Duration,
+};
+
+use anyhow::{anyhow, Context, Result};
+use axum::{
+    extract::{Multipart, Path as UrlPath, State},
+    http::{header, HeaderMap, StatusCode},
+    response::{Html, IntoResponse, Response},
+    routing::get,
+    Router,
+};
+use tokio::net::TcpListener;
+
+use crate::{
+    aoc::Answer,
+    explain::{self, Step},
+    input::{self, InputSource},
+    registry::{self, DayRegistration},
+    runner::{self, Limited, Limits},
+};
+
+pub const ADDRESS: &str = "127.0.0.1:8023";
+pub const STATIC_DIR: &str = "static";
+pub const HTMX_FILE: &str = "htmx.min.js";
+// seconds a parse or a part may take before the page gives up on it
+pub const TIMEOUT_SECS: f64 = 60.0;
+// htmx 1.9.3 as released, built in so in-page results need no setup and nothing is fetched from outside
+const HTMX: &[u8] = include_bytes!("../static/htmx.min.js");
+
+pub struct ServeConfig {
+    pub year: u32,
+    // files here are served under /static/, an htmx.min.js here replaces the built-in one
+    pub static_dir: PathBuf,
+    // every solve runs under these, so a runaway solver can't hold a request forever
+    pub limits: Limits,
+}
+
+pub fn router (config: ServeConfig) -> Router {
+    Router::new()
+        .route("/", get(index))
+        .route("/day/{year}/{day}", get(day_page).post(solve))
+        .route("/static/{file}", get(static_file))
+        .with_state(Arc::new(config))
+}
+
+pub async fn serve (listener: TcpListener, config: ServeConfig) -> Result<()> {
+    axum::serve(listener, router(config)).await.context("web server stopped")
+}
+
+fn escape (text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+fn page (title: &str, body: &str) -> Html<String> {
+    Html(format!(r#"<!doctype html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<title>{title}</title>
+<script src="/static/{htmx}" defer></script>
+<style>
+body {{ font-family: sans-serif; max-width: 60rem; margin: 2rem auto; padding: 0 1rem; }}
+table {{ border-collapse: collapse; }}
+td, th {{ border: 1px solid #ccc; padding: 0.2rem 0.6rem; text-align: left; }}
+textarea {{ width: 100%; font-family: monospace; }}
+pre.error {{ color: #b00; }}
+.htmx-indicator {{ display: none; }}
+.htmx-request .htmx-indicator {{ display: inline; }}
+</style>
+</head>
+<body>
+<p><a href="/">all days</a></p>
+{body}
+</body>
+</html>"#, title = escape(title), htmx = HTMX_FILE, body = body))
+}
+
+fn find_day (year: u32, day: &str) -> Result<&'static DayRegistration, (StatusCode, String)> {
+    registry::find(year, day).map_err(|e| (StatusCode::NOT_FOUND, format!("{:#}", e)))
+}
+
+async fn index (State(config): State<Arc<ServeConfig>>) -> Html<String> {
+    let mut body = format!("<h1>Advent of Code {}</h1>\n<ul>\n", config.year);
+    for day in registry::days(config.year) {
+        let _ = writeln!(body, r#"<li><a href="/day/{}/{}">day {}: {}</a></li>"#, day.year, day.day, day.day, escape(day.title));
+    }
+    body.push_str("</ul>\n");
+    let others: Vec<String> = registry::years().into_iter()
+        .filter(|y| *y != config.year)
+        .map(|y| y.to_string())
+        .collect();
+    if !others.is_empty() {
+        let _ = writeln!(body, "<p>also registered: {} (restart with --year)</p>", others.join(", "));
+    }
+    page(&format!("Advent of Code {}", config.year), &body)
+}
+
+fn day_form (registration: &DayRegistration) -> String {
+    let examples: String = (1..=registration.solver.examples().len())
+        .map(|n| format!(r#"<option value="{n}">example {n}</option>"#))
+        .collect();
+    format!(r##"<h1>{year} day {day}: {title}</h1>
+<form method="post" action="/day/{year}/{day}" enctype="multipart/form-data"
+      hx-post="/day/{year}/{day}" hx-encoding="multipart/form-data" hx-target="#result" hx-swap="innerHTML">
+<p><label>input file <input type="file" name="file"></label></p>
+<p><label>or paste it<br><textarea name="input" rows="12"></textarea></label></p>
+<p><label>or use <select name="example"><option value="">my input</option>{examples}</select></label></p>
+<p><label>part <select name="part"><option value="">both</option><option value="1">1</option><option value="2">2</option></select></label></p>
+<p><button type="submit">solve</button> <span class="htmx-indicator">solving...</span></p>
+</form>
+<div id="result">"##, year = registration.year, day = registration.day, title = escape(registration.title), examples = examples)
+}
+
+async fn day_page (UrlPath((year, day)): UrlPath<(u32, String)>) -> Response {
+    let registration = match find_day(year, &day) {
+        Ok(registration) => registration,
+        Err(not_found) => return not_found.into_response(),
+    };
+    let body = format!("{}</div>", day_form(registration));
+    page(&format!("day {}: {}", registration.day, registration.title), &body).into_response()
+}
+
+struct SolveRequest {
+    source: InputSource,
+    lines: Vec<String>,
+    parts: Vec<u8>,
+}
+
+// an uploaded file wins over pasted text, which wins over an example
+async fn read_form (registration: &DayRegistration, mut multipart: Multipart) -> Result<SolveRequest> {
+    let (mut file, mut pasted, mut example, mut parts) = (None, None, None, vec![1, 2]);
+    while let Some(field) = multipart.next_field().await.context("malformed form")? {
+        let name = field.name().unwrap_or_default().to_string();
+        let file_name = field.file_name().map(String::from);
+        let bytes = field.bytes().await.context("failed to read form field")?.to_vec();
+        let text = String::from_utf8_lossy(&bytes).trim().to_string();
+        match name.as_str() {
+            "file" if !bytes.is_empty() => file = Some((file_name.unwrap_or_else(|| "upload".to_string()), bytes)),
+            "input" if !text.is_empty() => pasted = Some(bytes),
+            "example" if !text.is_empty() => example = Some(text.parse::<usize>().context("invalid example number")?),
+            "part" if !text.is_empty() => parts = vec![text.parse::<u8>().context("invalid part")?],
+            _ => {}
+        }
+    }
+
+    let (source, lines) = match (file, pasted, example) {
+        (Some((name, bytes)), _, _) => {
+            let source = InputSource::File(PathBuf::from(name));
+            let lines = input::decode(bytes, &source)?;
+            (source, lines)
+        }
+        (None, Some(bytes), _) => {
+            let source = InputSource::File(PathBuf::from("pasted input"));
+            let lines = input::decode(bytes, &source)?;
+            (source, lines)
+        }
+        (None, None, Some(n)) => {
+            let example = registration.solver.examples().get(n.wrapping_sub(1))
+                .ok_or_else(|| anyhow!("day {} has no example {}", registration.day, n))?;
+            (InputSource::File(PathBuf::from(format!("example {}", n))), example.lines())
+        }
+        (None, None, None) => return Err(anyhow!("upload a file, paste the input or pick an example")),
+    };
+    Ok(SolveRequest { source, lines, parts })
+}
+
+struct PartResult {
+    part: u8,
+    answer: Answer,
+    elapsed: Duration,
+    steps: Vec<Step>,
+}
+
+// the phase's result, or why it was given up on
+fn outcome<T> (limited: Limited<T>, elapsed: Duration) -> Result<T> {
+    match limited {
+        Limited::Done(result) => result,
+        Limited::TimedOut => Err(anyhow!("timed out after {:.3?}", elapsed)),
+        Limited::Interrupted => Err(anyhow!("interrupted after {:.3?}", elapsed)),
+    }
+}
+
+// parses once then times each part with its explanation recorded, so the trace comes from the run being shown
+async fn solve_parts (registration: &'static DayRegistration, lines: Vec<String>, parts: &[u8], limits: &Limits) -> Result<(Duration, Vec<PartResult>)> {
+    let (parsed, parse_elapsed) = runner::limited(limits, move || registration.parse(&lines)).await;
+    let input = Arc::new(outcome(parsed, parse_elapsed)?);
+    let mut results = Vec::new();
+    for &part in parts {
+        let shared = Arc::clone(&input);
+        let (recorded, elapsed) = runner::limited(limits, move || Ok(explain::record(|| registration.part(part, &shared)))).await;
+        let (answer, steps) = match outcome(recorded, elapsed) {
+            Ok((answer, steps)) => (answer.unwrap_or_else(|e| Answer::Error(format!("{:#}", e))), steps),
+            Err(e) => (Answer::Error(format!("{:#}", e)), Vec::new()),
+        };
+        results.push(PartResult { part, answer, elapsed, steps });
+    }
+    Ok((parse_elapsed, results))
+}
+
+fn render_result (source: &InputSource, parse_elapsed: Duration, results: &[PartResult]) -> String {
+    let mut html = format!("<h2>{}</h2>\n<table>\n<tr><th>part</th><th>answer</th><th>time</th></tr>\n", escape(&source.to_string()));
+    let _ = writeln!(html, "<tr><td>parse</td><td></td><td>{:.3?}</td></tr>", parse_elapsed);
+    for result in results {
+        let answer = match &result.answer {
+            Answer::Error(reason) => format!(r#"<pre class="error">{}</pre>"#, escape(reason)),
+            answer => format!("<strong>{}</strong>", escape(&answer.to_string())),
+        };
+        let _ = writeln!(html, "<tr><td>{}</td><td>{}</td><td>{:.3?}</td></tr>", result.part, answer, result.elapsed);
+    }
+    html.push_str("</table>\n");
+
+    for result in results {
+        let _ = writeln!(html, "<details open>\n<summary>part {} explanation ({} steps)</summary>\n<ol>", result.part, result.steps.len());
+        for step in &result.steps {
+            let _ = writeln!(html, "<li><b>{}</b>: {}</li>", escape(&step.subject), escape(&step.detail));
+        }
+        html.push_str("</ol>\n</details>\n");
+    }
+    html
+}
+
+fn render_error (error: &anyhow::Error) -> String {
+    format!(r#"<pre class="error">{}</pre>"#, escape(&format!("{:#}", error)))
+}
+
+// htmx asks for just the result fragment, a plain form post gets the whole page back
+async fn solve (State(config): State<Arc<ServeConfig>>, UrlPath((year, day)): UrlPath<(u32, String)>, headers: HeaderMap, multipart: Multipart) -> Response {
+    let registration = match find_day(year, &day) {
+        Ok(registration) => registration,
+        Err(not_found) => return not_found.into_response(),
+    };
+
+    let fragment = match read_form(registration, multipart).await {
+        Ok(request) => match solve_parts(registration, request.lines, &request.parts, &config.limits).await {
+            Ok((parse_elapsed, results)) => render_result(&request.source, parse_elapsed, &results),
+            Err(e) => render_error(&e),
+        },
+        Err(e) => render_error(&e),
+    };
+
+    if headers.contains_key("hx-request") {
+        Html(fragment).into_response()
+    } else {
+        let body = format!("{}{}</div>", day_form(registration), fragment);
+        page(&format!("day {}: {}", registration.day, registration.title), &body).into_response()
+    }
+}
+
+async fn static_file (State(config): State<Arc<ServeConfig>>, UrlPath(file): UrlPath<String>) -> Response {
+    // only plain file names, nothing that could walk out of the static dir
+    if file.contains(['/', '\\']) || file.starts_with('.') {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+    let bytes = match tokio::fs::read(config.static_dir.join(&file)).await {
+        Ok(bytes) => bytes,
+        Err(_) if file == HTMX_FILE => HTMX.to_vec(),
+        Err(_) => return StatusCode::NOT_FOUND.into_response(),
+    };
+    let content_type = if file.ends_with(".js") { "text/javascript" } else { "application/octet-stream" };
+    ([(header::CONTENT_TYPE, content_type)], bytes).into_response()
+}
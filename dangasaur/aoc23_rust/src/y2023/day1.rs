@@ -0,0 +1,99 @@
+use anyhow::{Context, Result};
+use std::collections::HashMap;
+use regex::{Regex, Match};
+
+use crate::aoc::{Answer, Example, Solveable};
+use crate::error::AocError;
+use crate::{explain, register_day};
+
+const EXAMPLE_1: &str = "\
+1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet";
+
+const EXAMPLE_2: &str = "\
+two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen";
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Day1 {}
+register_day!(2023, 1, "Trebuchet?!", ["trebuchet"], Day1 {});
+
+impl Solveable for Day1 {
+    type Input = Vec<String>;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example { input: EXAMPLE_1, part_1: Some("142"), part_2: None },
+        Example { input: EXAMPLE_2, part_1: None, part_2: Some("281") },
+    ];
+
+    fn parse (&self, lines: &[String]) -> Result<Self::Input> {
+        Ok(lines.to_vec())
+    }
+
+    fn part_1 (&self, lines: &Self::Input) -> Result<Answer> {
+        let re_numbers = Regex::new(r"\d").context("invalid digit regex pattern")?;
+
+        let sum_part1 = lines.iter().enumerate().try_fold(0, |memo, (i, next)| {
+            let str_numbers: Vec<&str> = re_numbers.find_iter(next).map(|n| n.as_str()).collect();
+            let (Some(first), Some(last)) = (str_numbers.first(), str_numbers.last()) else {
+                return Err(AocError::new("calibration line has no digits").at_line(i, next));
+            };
+            let combined = format!("{}{}", first, last).parse::<i32>()
+                .map_err(|e| AocError::new(format!("failed to parse calibration value: {}", e)).at_line(i, next))?;
+            explain!(format!("line {}", i + 1), "{}: first digit {}, last digit {} => {}", next, first, last, combined);
+            Ok(combined + memo)
+        })?;
+
+        Ok(sum_part1.into())
+    }
+
+    fn part_2 (&self, lines: &Self::Input) -> Result<Answer> {
+        let number_map = HashMap::from([
+            ("0", 0), ("zero", 0),
+            ("1", 1), ("one", 1),
+            ("2", 2), ("two", 2),
+            ("3", 3), ("three", 3),
+            ("4", 4), ("four", 4),
+            ("5", 5), ("five", 5),
+            ("6", 6), ("six", 6),
+            ("7", 7), ("seven", 7),
+            ("8", 8), ("eight", 8),
+            ("9", 9), ("nine", 9)
+        ]);
+
+        let patterns = [r"\d", r"one", r"two", r"three", r"four", r"five", r"six", r"seven", r"eight", r"nine"]
+            .into_iter()
+            .map(|p| Regex::new(p).with_context(|| format!("invalid number regex pattern {}", p)))
+            .collect::<Result<Vec<_>>>()?;
+
+        let sum_part2 = lines.iter().enumerate().try_fold(0, |memo, (i, next)| {
+            // for each regular expression get matches
+            let matches: Vec<Match> = patterns.iter()
+                .flat_map(|p| p.find_iter(next).collect::<Vec<Match>>())
+                .collect();
+
+            let first_match = matches.iter().reduce(|memo, next| if next.start() < memo.start() { next } else { memo });
+            let last_match = matches.iter().reduce(|memo, next| if next.start() > memo.start() { next } else { memo });
+            let (Some(first_match), Some(last_match)) = (first_match, last_match) else {
+                return Err(AocError::new("calibration line has no digits or spelled out numbers").at_line(i, next));
+            };
+
+            let digit = |m: &Match| number_map.get(m.as_str()).copied()
+                .ok_or_else(|| AocError::new(format!("'{}' is not a number", m.as_str())).at_line(i, next).span(m.range()));
+            let combined = digit(first_match)? * 10 + digit(last_match)?;
+            explain!(format!("line {}", i + 1), "{}: first number '{}' at column {}, last number '{}' at column {} => {}",
+                next, first_match.as_str(), first_match.start() + 1, last_match.as_str(), last_match.start() + 1, combined);
+            Ok(combined + memo)
+        })?;
+
+        Ok(sum_part2.into())
+    }
+}
+
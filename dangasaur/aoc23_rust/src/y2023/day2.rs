@@ -0,0 +1,150 @@
+use anyhow::{Result, Context};
+use regex::Regex;
+use tracing::{debug, trace};
+
+use crate::aoc::{Answer, Example, Solveable};
+use crate::error::AocError;
+use crate::{explain, register_day};
+
+#[derive(Debug)]
+struct Roll {
+    roll_string: String,
+    red: Option<i32>,
+    green: Option<i32>,
+    blue: Option<i32>,
+}
+
+#[derive(Debug)]
+pub struct Game {
+    id: i32,
+    max_red: i32,
+    max_green: i32,
+    max_blue: i32,
+}
+
+const EXAMPLE: &str = "\
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Day2 {}
+register_day!(2023, 2, "Cube Conundrum", ["cubes"], Day2 {});
+
+impl Solveable for Day2 {
+    type Input = Vec<Game>;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example { input: EXAMPLE, part_1: Some("8"), part_2: Some("2286") },
+    ];
+
+    fn parse (&self, lines: &[String]) -> Result<Self::Input> {
+        let game_id_pattern = Regex::new(r"\d+").context("invalid game ID regular expression")?;
+        let rgb_pattern = Regex::new(r"(\d+)\s(red|green|blue)").context("invalid rgb regular expression")?;
+
+        let mut games: Vec<Game> = Vec::new();
+
+        // split the line by : to get the game number and the rolls string
+        for (i, line) in lines.iter().enumerate() {
+            let (right, left) = line.split_once(':')
+                .ok_or_else(|| AocError::new("game line invalid, cannot split on :").at_line(i, line))?;
+            let game_id_match = game_id_pattern.find(right)
+                .ok_or_else(|| AocError::new("failed to find game ID in line").at_line(i, line).span(0..right.len()))?;
+            let game_id = game_id_match.as_str().parse::<i32>()
+                .map_err(|e| AocError::new(format!("failed to parse game ID to integer: {}", e)).at_line(i, line).span(game_id_match.range()))?;
+
+            // track where each roll starts in the line so errors can point at it
+            let mut offset = right.len() + 1;
+            let rolls = left.split(';')
+                .map(|roll_string| {
+                    let roll_offset = offset;
+                    offset += roll_string.len() + 1;
+
+                    let mut roll = Roll {
+                        roll_string: roll_string.to_string(),
+                        red: None,
+                        green: None,
+                        blue: None,
+                    };
+
+                    for captures in rgb_pattern.captures_iter(roll_string) {
+                        let (number, color) = (&captures[1], &captures[2]);
+                        let span = captures.get(0).map_or(0..roll_string.len(), |m| m.range());
+                        let span = roll_offset + span.start..roll_offset + span.end;
+                        let number_i32 = number.parse::<i32>()
+                            .map_err(|e| AocError::new(format!("failed to parse roll number: {}", e)).at_line(i, line).span(span.clone()))?;
+                        match color {
+                            "red" => roll.red = Some(number_i32),
+                            "green" => roll.green = Some(number_i32),
+                            "blue" => roll.blue = Some(number_i32),
+                            _ => return Err(AocError::new(format!("unknown cube color '{}', expected red, green, or blue", color)).at_line(i, line).span(span)),
+                        }
+                    }
+
+                    trace!(red = ?roll.red, green = ?roll.green, blue = ?roll.blue, roll = roll.roll_string.trim(), "parsed roll");
+                    Ok(roll)
+                })
+                .collect::<Result<Vec<_>, AocError>>()?;
+
+            // find the maximum and minimum rolls
+            let mut rgb_max = (0, 0, 0);
+            for roll in &rolls {
+                if let Some(red) = roll.red {
+                    if red > rgb_max.0 { rgb_max.0 = red }
+                }
+
+                if let Some(green) = roll.green {
+                    if green > rgb_max.1 { rgb_max.1 = green }
+                }
+
+                if let Some(blue) = roll.blue {
+                    if blue > rgb_max.2 { rgb_max.2 = blue }
+                }
+            }
+
+            let game = Game {
+                id: game_id,
+                max_red: rgb_max.0,
+                max_green: rgb_max.1,
+                max_blue: rgb_max.2,
+            };
+            debug!(game = game.id, max_red = game.max_red, max_green = game.max_green, max_blue = game.max_blue, "parsed game");
+            games.push(game)
+        }
+
+        Ok(games)
+    }
+
+    // part1: identify which games are possible based on provided max rolls
+    fn part_1 (&self, games: &Self::Input) -> Result<Answer> {
+        let possible_games_sum: i32 = games.iter()
+            .filter_map(|g| {
+                let possible = g.max_red <= 12 && g.max_green <= 13 && g.max_blue <=14;
+                explain!(format!("game {}", g.id), "max red {}, green {}, blue {} => {} (limits 12 red, 13 green, 14 blue)",
+                    g.max_red, g.max_green, g.max_blue, if possible { "possible" } else { "impossible" });
+                if possible {
+                    Some(g.id)
+                } else {
+                    None
+                }
+            })
+            .sum();
+
+        Ok(possible_games_sum.into())
+    }
+
+    // part2: identify the minimum games
+    fn part_2 (&self, games: &Self::Input) -> Result<Answer> {
+        let minimum_cubes_sum: i32 = games.iter()
+            .map(|g| {
+                let power = g.max_red * g.max_green * g.max_blue;
+                explain!(format!("game {}", g.id), "needs at least {} red, {} green, {} blue => power {}", g.max_red, g.max_green, g.max_blue, power);
+                power
+            })
+            .sum();
+
+        Ok(minimum_cubes_sum.into())
+    }
+}
@@ -0,0 +1,229 @@
+use anyhow::{Result, Context};
+use regex::Regex;
+
+use crate::aoc::{Answer, Example, Solveable};
+use crate::error::AocError;
+use crate::{explain, register_day};
+
+#[derive(Debug)]
+struct SchematicPart {
+    start_pos: usize,
+    end_pos: usize,
+    value: i32,
+}
+
+#[derive(Debug)]
+pub struct SchematicLine {
+    numbers: Vec<SchematicPart>,
+    symbols: Vec<usize>,
+    gear_symbols: Vec<usize>,
+}
+
+#[derive(Debug)]
+struct Window<'a> {
+    behind: Option<&'a SchematicLine>,
+    current: Option<&'a SchematicLine>,
+    ahead: Option<&'a SchematicLine>,
+}
+
+fn is_adjacent(symbol_pos: &usize, num_start_pos: &usize, num_end_pos: &usize) -> bool {
+    // this prevents issues if the match start position is 0
+    let start_floor: usize = if *num_start_pos == usize::MIN { 0 } else { num_start_pos - 1 };
+    symbol_pos >= &start_floor && symbol_pos <= num_end_pos
+}
+
+const EXAMPLE: &str = "\
+467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Day3 {}
+register_day!(2023, 3, "Gear Ratios", ["gears"], Day3 {});
+
+impl Solveable for Day3 {
+    type Input = Vec<SchematicLine>;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example { input: EXAMPLE, part_1: Some("4361"), part_2: Some("467835") },
+    ];
+
+    fn parse (&self, lines: &[String]) -> Result<Self::Input> {
+        let re_number = Regex::new(r"\d+").context("invalide number regex pattern")?;
+        let re_symbol = Regex::new(r"[^\d.]").context("invalid symbol regex pattern")?;
+        let re_gear_symbol = Regex::new(r"[*]").context("invalid gear symbol regex pattern")?;
+
+        lines.iter().enumerate().map(|(i, line)| {
+            let numbers = re_number.find_iter(line)
+                .map(|m| {
+                    Ok(SchematicPart {
+                        start_pos: m.start(),
+                        end_pos: m.end(),
+                        value: m.as_str().parse::<i32>()
+                            .map_err(|e| AocError::new(format!("failed to parse value for part: {}", e)).at_line(i, line).span(m.range()))?,
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            let symbols: Vec<usize> = re_symbol.find_iter(line)
+                .map(|m| {
+                    m.start()
+                })
+                .collect();
+
+            let gear_symbols: Vec<usize> = re_gear_symbol.find_iter(line)
+                .map(|m| {
+                    m.start()
+                })
+                .collect();
+
+            let schematic_line = SchematicLine {
+                numbers,
+                symbols,
+                gear_symbols,
+            };
+            Ok(schematic_line)
+        })
+        .collect::<Result<Vec<_>>>()
+    }
+
+    fn part_1 (&self, schematic_lines: &Self::Input) -> Result<Answer> {
+        let mut parts: Vec<i32> = Vec::new();
+        let mut window = Window {
+            behind: None,
+            current: None,
+            ahead: None,
+        };
+
+        let mut schematic_line_iter = schematic_lines.iter();
+
+        // load the first two lines into the window to get started, row is the current line's 0 based index
+        window.current = schematic_line_iter.next();
+        window.ahead = schematic_line_iter.next();
+        let mut row = 0;
+
+        // while the window has a current line, identify parts
+        while let Some(current_line) = window.current {
+            // for each number determine frame then search for any symbols in frame, 0 indexing
+            let identified_parts: Vec<i32> = current_line.numbers.iter().filter_map(|n| {
+                let mut found_symbol = current_line.symbols.iter().find(|s| { is_adjacent(s, &n.start_pos, &n.end_pos)}).map(|s| (row, s));
+
+                if found_symbol.is_none() {
+                    if let Some(behind_line) = window.behind {
+                        found_symbol = behind_line.symbols.iter().find(|s| { is_adjacent(s, &n.start_pos, &n.end_pos)}).map(|s| (row - 1, s));
+                    }
+                }
+
+                if found_symbol.is_none() {
+                    if let Some(ahead_line) = window.ahead {
+                        found_symbol = ahead_line.symbols.iter().find(|s| { is_adjacent(s, &n.start_pos, &n.end_pos)}).map(|s| (row + 1, s));
+                    }
+                }
+
+                found_symbol.map(|(symbol_row, symbol_pos)| {
+                    explain!(format!("part {}", n.value), "row {}, columns {}-{}, next to the symbol at row {}, column {}",
+                        row + 1, n.start_pos + 1, n.end_pos, symbol_row + 1, symbol_pos + 1);
+                    n.value
+                })
+            }).collect();
+
+            for p in identified_parts {
+                parts.push(p);
+            }
+
+            // shift the window "down"
+            window.behind = window.current;
+            window.current = window.ahead;
+            window.ahead = schematic_line_iter.next();
+            row += 1;
+        }
+
+        Ok(parts.into_iter().sum::<i32>().into())
+    }
+
+    fn part_2 (&self, schematic_lines: &Self::Input) -> Result<Answer> {
+        let mut gears: Vec<i32> = Vec::new();
+        let mut window = Window {
+            behind: None,
+            current: None,
+            ahead: None,
+        };
+
+        let mut schematic_line_iter = schematic_lines.iter();
+
+        // load the first two lines into the window to get started
+        window.current = schematic_line_iter.next();
+        window.ahead = schematic_line_iter.next();
+        let mut row = 0;
+
+        // while the window has a current line, identify any gears
+        while let Some(current_line) = window.current {
+            if !current_line.gear_symbols.is_empty() {
+                // for each gear symbol on the line, check all lines for adjacent numbers, if there are only two it's a gear
+                let line_gears: Vec<i32> = current_line.gear_symbols.iter()
+                    .filter_map(|gs| {
+                        let mut numbers_found: Vec<i32> = Vec::new();
+                        let mut current_line_numbers: Vec<i32> = current_line.numbers.iter().filter_map(|n| {
+                            if is_adjacent(gs, &n.start_pos, &n.end_pos) {
+                                Some(n.value)
+                            } else {
+                                None
+                            }
+                        }).collect();
+                        numbers_found.append(&mut current_line_numbers);
+
+
+                        if let Some(behind_line) = window.behind {
+                            let mut behind_line_numbers: Vec<i32> = behind_line.numbers.iter().filter_map(|n| {
+                                if is_adjacent(gs, &n.start_pos, &n.end_pos) {
+                                    Some(n.value)
+                                } else {
+                                    None
+                                }
+                            }).collect();
+                            numbers_found.append(&mut behind_line_numbers);
+                        }
+
+                        if let Some(ahead_line) = window.ahead {
+                            let mut ahead_line_numbers: Vec<i32> = ahead_line.numbers.iter().filter_map(|n| {
+                                if is_adjacent(gs, &n.start_pos, &n.end_pos) {
+                                    Some(n.value)
+                                } else {
+                                    None
+                                }
+                            }).collect();
+                            numbers_found.append(&mut ahead_line_numbers);
+                        }
+
+                        if numbers_found.len() == 2 {
+                            let ratio = numbers_found.iter().product();
+                            explain!(format!("gear at row {}, column {}", row + 1, gs + 1), "{} * {} = {}", numbers_found[0], numbers_found[1], ratio);
+                            Some(ratio)
+                        } else {
+                            None
+                        }
+                    })
+                    .collect();
+
+                for g in line_gears {
+                    gears.push(g)
+                }
+            }
+
+            // shift window
+            window.behind = window.current;
+            window.current = window.ahead;
+            window.ahead = schematic_line_iter.next();
+            row += 1;
+        }
+
+        Ok(gears.into_iter().sum::<i32>().into())
+    }
+}
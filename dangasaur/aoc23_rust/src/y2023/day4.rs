@@ -0,0 +1,181 @@
+use std::collections::HashMap;
+
+use anyhow::{Context, Result};
+use regex::Regex;
+use tracing::{debug, trace};
+
+use crate::aoc::{Answer, Example, Solveable};
+use crate::error::AocError;
+use crate::{cancel, explain, register_day};
+
+#[derive(Debug)]
+pub struct Card {
+    id: i32,
+    winning_numbers: Vec<i32>,
+    my_numbers: Vec<i32>,
+}
+
+impl Card {
+    fn num_matches(&self) -> usize {
+        self.winning_numbers
+            .iter()
+            .filter(|n| self.my_numbers.contains(n))
+            .count()
+    }
+
+    fn score(&self) -> i32 {
+        let num_matches = self.num_matches();
+        let mut score = 0;
+        if num_matches > 0 {
+            score = 1;
+            for _ in 1..num_matches {
+                score *= 2;
+            }
+        }
+        score
+    }
+}
+
+const EXAMPLE: &str = "\
+Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Day4 {}
+register_day!(2023, 4, "Scratchcards", ["scratchcards"], Day4 {});
+
+impl Solveable for Day4 {
+    type Input = Vec<Card>;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example { input: EXAMPLE, part_1: Some("13"), part_2: Some("30") },
+    ];
+
+    fn parse(&self, lines: &[String]) -> Result<Self::Input> {
+        let number_pattern = Regex::new(r"\d+").context("invalid number pattern")?;
+
+        lines
+            .iter()
+            .enumerate()
+            .map(|(i, line)| {
+                let (head, body) = line
+                    .split_once(':')
+                    .ok_or_else(|| AocError::new("card line invalid, cannot split on :").at_line(i, line))?;
+                let card_id_match = number_pattern
+                    .find(head)
+                    .ok_or_else(|| AocError::new("failed to find card id").at_line(i, line).span(0..head.len()))?;
+                let card_id = card_id_match
+                    .as_str()
+                    .parse::<i32>()
+                    .map_err(|e| {
+                        AocError::new(format!("failed to parse card id: {}", e))
+                            .at_line(i, line)
+                            .span(card_id_match.range())
+                    })?;
+
+                let (win_s, my_s) = body
+                    .split_once('|')
+                    .ok_or_else(|| AocError::new("failed to split card body on |").at_line(i, line))?;
+
+                // offsets of each number list within the line, for pointing at a bad number
+                let win_offset = head.len() + 1;
+                let my_offset = win_offset + win_s.len() + 1;
+                let parse_numbers = |numbers: &str, offset: usize, what: &str| {
+                    number_pattern
+                        .find_iter(numbers)
+                        .map(|n| {
+                            n.as_str().parse::<i32>().map_err(|e| {
+                                AocError::new(format!("failed to parse {} number: {}", what, e))
+                                    .at_line(i, line)
+                                    .span(offset + n.start()..offset + n.end())
+                            })
+                        })
+                        .collect::<Result<Vec<_>, AocError>>()
+                };
+                let winning_numbers = parse_numbers(win_s, win_offset, "winning")?;
+                let my_numbers = parse_numbers(my_s, my_offset, "my")?;
+
+                let card = Card {
+                    id: card_id,
+                    winning_numbers,
+                    my_numbers,
+                };
+                trace!(?card, "parsed card");
+
+                Ok(card)
+            })
+            .collect::<Result<Vec<_>>>()
+    }
+
+    fn part_1(&self, cards: &Self::Input) -> Result<Answer> {
+        let part1_score: i32 = cards
+            .iter()
+            .map(|c| {
+                let score = c.score();
+                explain!(format!("card {}", c.id), "{} matching numbers => {} points", c.num_matches(), score);
+                score
+            })
+            .sum();
+
+        Ok(part1_score.into()) // part 1 23673
+    }
+
+    fn part_2(&self, cards: &Self::Input) -> Result<Answer> {
+        // initialize an id:count map with all positions having an initial count of 1 (the original cards)
+        let mut card_counts: HashMap<i32, i32> =
+            cards.iter().fold(HashMap::new(), |mut memo, next| {
+                memo.insert(next.id, 1);
+                memo
+            });
+
+        // for each card get the count and then increment the next n cards by the number of matching numbers, any expansion should have a count of zero
+        for card in cards {
+            cancel::check()?;
+            let current_card_count = *card_counts
+                .get(&card.id)
+                .context("card count not found")?;
+            let num_matches = i32::try_from(card.num_matches())
+                .with_context(|| format!("card {} has too many matches", card.id))?;
+            debug!(
+                card = card.id,
+                copies = current_card_count,
+                matches = num_matches,
+                "scoring card"
+            );
+
+            explain!(
+                format!("card {}", card.id),
+                "{} copies, {} matches{}",
+                current_card_count,
+                num_matches,
+                if num_matches > 0 {
+                    format!(" => +{} copies of cards {}-{}", current_card_count, card.id + 1, card.id + num_matches)
+                } else {
+                    String::new()
+                }
+            );
+
+            if num_matches > 0 {
+                let next_cards: Vec<i32> = (card.id + 1..card.id + num_matches + 1).collect();
+                trace!(
+                    copies = current_card_count,
+                    cards = ?next_cards,
+                    "adding copies"
+                );
+                for id in next_cards {
+                    card_counts
+                        .entry(id)
+                        .and_modify(|count| *count += current_card_count)
+                        .or_insert(0);
+                }
+            }
+        }
+        let total_num_cards: i32 = card_counts.values().sum();
+
+        Ok(total_num_cards.into())
+    }
+}
@@ -0,0 +1,658 @@
+use std::{fmt, ops::Range, str::FromStr};
+
+use anyhow::{anyhow, bail, Context, Result};
+use regex::Regex;
+use tracing::debug;
+use strum_macros::{Display, EnumString};
+
+use crate::aoc::{Answer, Example, Solveable};
+use crate::error::AocError;
+use crate::{cancel, explain, register_day};
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, EnumString, Display)]
+pub enum FoodCategory {
+    #[strum(serialize = "seed")]
+    Seed,
+    #[strum(serialize = "soil")]
+    Soil,
+    #[strum(serialize = "fertilizer")]
+    Fertilizer,
+    #[strum(serialize = "water")]
+    Water,
+    #[strum(serialize = "light")]
+    Light,
+    #[strum(serialize = "temperature")]
+    Temperature,
+    #[strum(serialize = "humidity")]
+    Humidity,
+    #[strum(serialize = "location")]
+    Location,
+}
+
+struct MapExpression {
+    source_start: u64,
+    destination_start: u64,
+    range_length: u64,
+    // the input line it was read from, an overflow points back at it
+    line: usize,
+    text: String,
+}
+
+impl MapExpression {
+    fn overflow (&self, what: String) -> AocError {
+        AocError::new(format!("{} overflows a 64-bit number", what)).at_line(self.line, &self.text)
+    }
+
+    fn source_end (&self) -> Result<u64, AocError> {
+        self.source_start.checked_add(self.range_length)
+            .ok_or_else(|| self.overflow(format!("source range end {} + {}", self.source_start, self.range_length)))
+    }
+
+    // number must be at or past source_start
+    fn destination (&self, number: u64) -> Result<u64, AocError> {
+        self.destination_start.checked_add(number - self.source_start)
+            .ok_or_else(|| self.overflow(format!("destination {} + {}", self.destination_start, number - self.source_start)))
+    }
+}
+
+struct FoodCategoryMap {
+    input: FoodCategory,
+    output: FoodCategory,
+    direct_maps: Vec<MapExpression>,
+}
+
+pub trait CategoryMapper {
+    fn map_number (&self, number: u64) -> Result<u64>;
+    fn map_range (&self, range: Range<u64>) -> Result<Vec<Range<u64>>>;
+    // the inverse of map_range, every range of inputs that lands inside range
+    fn preimage (&self, range: Range<u64>) -> Result<Vec<Range<u64>>>;
+}
+
+// sorts ranges and joins the ones that touch or overlap
+fn merge (mut ranges: Vec<Range<u64>>) -> Vec<Range<u64>> {
+    ranges.sort_by_key(|r| r.start);
+    let mut merged: Vec<Range<u64>> = Vec::with_capacity(ranges.len());
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+            _ => merged.push(range),
+        }
+    }
+    merged
+}
+
+impl CategoryMapper for FoodCategoryMap {
+    fn map_number (&self, number: u64) -> Result<u64> {
+        // logic: if the number is covered by any direct map source range, use the position of the number in that range to determine the output, else return the number
+        let direct_map = self.direct_maps.iter().find(|dm| {
+            number >= dm.source_start && number - dm.source_start < dm.range_length
+        });
+        match direct_map {
+            Some(dm) => Ok(dm.destination(number)?),
+            None => Ok(number)
+        }
+    }
+
+    fn map_range (&self, range: Range<u64>) -> Result<Vec<Range<u64>>> {
+        // logic: split what is left of the range against each direct map, the overlap is shifted to the destination
+        // and the pieces either side are tried against the next map, whatever no map covers comes out unchanged
+        let mut mapped = Vec::new();
+        let mut unmapped = vec![range];
+        for dm in &self.direct_maps {
+            let source_end = dm.source_end()?;
+            let mut remaining = Vec::new();
+            for piece in unmapped {
+                let (start, end) = (piece.start.max(dm.source_start), piece.end.min(source_end));
+                if start >= end {
+                    remaining.push(piece);
+                    continue;
+                }
+                mapped.push(dm.destination(start)?..dm.destination(end)?);
+                if piece.start < start {
+                    remaining.push(piece.start..start);
+                }
+                if end < piece.end {
+                    remaining.push(end..piece.end);
+                }
+            }
+            unmapped = remaining;
+        }
+        mapped.extend(unmapped);
+        Ok(mapped)
+    }
+
+    fn preimage (&self, range: Range<u64>) -> Result<Vec<Range<u64>>> {
+        // logic: whatever lands inside a direct map's destination came from the same stretch of its source,
+        // and the numbers no source covers came from themselves
+        let mut sources = Vec::new();
+        let mut uncovered = vec![range.clone()];
+        for dm in &self.direct_maps {
+            let source_end = dm.source_end()?;
+            let destination_end = dm.destination(source_end)?;
+            let (start, end) = (range.start.max(dm.destination_start), range.end.min(destination_end));
+            if start < end {
+                sources.push(dm.source_start + (start - dm.destination_start)..dm.source_start + (end - dm.destination_start));
+            }
+
+            let mut remaining = Vec::new();
+            for piece in uncovered {
+                if piece.start < dm.source_start {
+                    remaining.push(piece.start..piece.end.min(dm.source_start));
+                }
+                if piece.end > source_end {
+                    remaining.push(piece.start.max(source_end)..piece.end);
+                }
+            }
+            uncovered = remaining;
+        }
+        sources.extend(uncovered);
+        Ok(merge(sources))
+    }
+}
+
+// numbers from start up to the next segment's start are shifted onto destination onwards
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Segment {
+    start: u64,
+    destination: u64,
+}
+
+impl Segment {
+    fn shift (&self) -> i128 {
+        self.destination as i128 - self.start as i128
+    }
+}
+
+// one past u64::MAX, where the last segment ends
+const DOMAIN_END: u128 = 1 << 64;
+
+/// A whole category to category mapping as sorted segments covering every u64, so a lookup is one binary search
+/// and chains of maps compose into a single map, e.g. `almanac.compose(FoodCategory::Soil, FoodCategory::Humidity)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PiecewiseMap {
+    pub from: FoodCategory,
+    pub to: FoodCategory,
+    // sorted by start, the first starts at 0 and neighbours never share a shift
+    segments: Vec<Segment>,
+}
+
+impl PiecewiseMap {
+    pub fn identity (category: FoodCategory) -> Self {
+        PiecewiseMap { from: category, to: category, segments: vec![Segment { start: 0, destination: 0 }] }
+    }
+
+    // segments must be sorted, contiguous and start at 0, neighbours shifting by the same amount are merged
+    fn new (from: FoodCategory, to: FoodCategory, segments: Vec<Segment>) -> Self {
+        let mut merged: Vec<Segment> = Vec::with_capacity(segments.len());
+        for segment in segments {
+            if merged.last().is_none_or(|last| last.shift() != segment.shift()) {
+                merged.push(segment);
+            }
+        }
+        PiecewiseMap { from, to, segments: merged }
+    }
+
+    // the unlisted gaps between direct maps map to themselves, overlapping sources or ranges past u64::MAX are errors
+    fn from_map (map: &FoodCategoryMap) -> Result<Self> {
+        let mut direct_maps: Vec<&MapExpression> = map.direct_maps.iter().filter(|dm| dm.range_length > 0).collect();
+        direct_maps.sort_by_key(|dm| dm.source_start);
+
+        let mut segments = Vec::new();
+        let mut covered = 0;
+        for dm in direct_maps {
+            if dm.source_start < covered {
+                return Err(AocError::new(format!("source range overlaps an earlier line of the {}-to-{} map", map.input, map.output))
+                    .at_line(dm.line, &dm.text).into());
+            }
+            let source_end = dm.source_end()?;
+            // the last number of the range has to land inside u64 as well
+            dm.destination(source_end - 1)?;
+            if dm.source_start > covered {
+                segments.push(Segment { start: covered, destination: covered });
+            }
+            segments.push(Segment { start: dm.source_start, destination: dm.destination_start });
+            covered = source_end;
+        }
+        segments.push(Segment { start: covered, destination: covered });
+        Ok(PiecewiseMap::new(map.input, map.output, segments))
+    }
+
+    fn end (&self, index: usize) -> u128 {
+        self.segments.get(index + 1).map_or(DOMAIN_END, |s| s.start as u128)
+    }
+
+    fn index (&self, number: u64) -> usize {
+        self.segments.partition_point(|s| s.start <= number) - 1
+    }
+
+    pub fn get (&self, number: u64) -> u64 {
+        let segment = self.segments[self.index(number)];
+        // every segment was checked to land inside u64 when it was built
+        segment.destination + (number - segment.start)
+    }
+
+    /// every stage in turn starting from `from`, e.g. the seed to soil, ..., humidity to location maps give seed to location
+    pub fn compose_all (from: FoodCategory, stages: &[PiecewiseMap]) -> Result<PiecewiseMap> {
+        stages.iter().try_fold(PiecewiseMap::identity(from), |composed, stage| composed.compose(stage))
+    }
+
+    /// self then other, e.g. seed to soil composed with soil to fertilizer gives seed to fertilizer
+    pub fn compose (&self, other: &PiecewiseMap) -> Result<PiecewiseMap> {
+        if self.to != other.from {
+            bail!("can't follow {} to {} with {} to {}", self.from, self.to, other.from, other.to);
+        }
+        let mut segments = Vec::new();
+        for (i, segment) in self.segments.iter().enumerate() {
+            let (start, end) = (segment.start as u128, self.end(i));
+            // split this segment wherever its image crosses one of other's segments
+            let mut source = start;
+            while source < end {
+                let image = segment.destination as u128 + (source - start);
+                let j = other.index(image as u64);
+                let length = (end - source).min(other.end(j) - image);
+                segments.push(Segment { start: source as u64, destination: other.get(image as u64) });
+                source += length;
+            }
+        }
+        Ok(PiecewiseMap::new(self.from, other.to, segments))
+    }
+
+    /// The same mapping run backwards, only possible when every number is reached exactly once
+    pub fn invert (&self) -> Result<PiecewiseMap> {
+        let mut pieces: Vec<(u128, u128, u64)> = self.segments.iter().enumerate()
+            .map(|(i, s)| (s.destination as u128, self.end(i) - s.start as u128, s.start))
+            .collect();
+        pieces.sort();
+
+        let mut expected = 0;
+        for &(destination, length, _) in &pieces {
+            if destination != expected {
+                bail!("{} to {} can't be inverted, {} is {}", self.from, self.to, destination.min(expected),
+                    if destination > expected { "never reached" } else { "reached more than once" });
+            }
+            expected = destination + length;
+        }
+        if expected != DOMAIN_END {
+            bail!("{} to {} can't be inverted, {} and above are never reached", self.from, self.to, expected);
+        }
+
+        let segments = pieces.into_iter()
+            .map(|(destination, _, start)| Segment { start: destination as u64, destination: start })
+            .collect();
+        Ok(PiecewiseMap::new(self.to, self.from, segments))
+    }
+}
+
+impl CategoryMapper for PiecewiseMap {
+    fn map_number (&self, number: u64) -> Result<u64> {
+        Ok(self.get(number))
+    }
+
+    fn map_range (&self, range: Range<u64>) -> Result<Vec<Range<u64>>> {
+        let mut mapped = Vec::new();
+        let mut start = range.start;
+        while start < range.end {
+            let i = self.index(start);
+            let end = self.end(i).min(range.end as u128) as u64;
+            let destination = self.get(start);
+            let destination_end = destination.checked_add(end - start)
+                .with_context(|| format!("{}..{} maps past the end of u64", start, end))?;
+            mapped.push(destination..destination_end);
+            start = end;
+        }
+        Ok(mapped)
+    }
+
+    fn preimage (&self, range: Range<u64>) -> Result<Vec<Range<u64>>> {
+        let to_u64 = |n: u128| u64::try_from(n).context("pre-image reaches past the end of u64");
+        let mut sources = Vec::new();
+        for (i, segment) in self.segments.iter().enumerate() {
+            let (start, destination) = (segment.start as u128, segment.destination as u128);
+            let destination_end = destination + (self.end(i) - start);
+            let (low, high) = ((range.start as u128).max(destination), (range.end as u128).min(destination_end));
+            if low < high {
+                sources.push(to_u64(start + (low - destination))?..to_u64(start + (high - destination))?);
+            }
+        }
+        Ok(merge(sources))
+    }
+}
+
+// one line per segment, "50..98 => 52..100", the last one open ended
+impl fmt::Display for PiecewiseMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} to {}", self.from, self.to)?;
+        for (i, segment) in self.segments.iter().enumerate() {
+            let end = self.end(i);
+            let destination_end = segment.destination as u128 + (end - segment.start as u128);
+            let bound = |n: u128| if n >= DOMAIN_END { String::new() } else { n.to_string() };
+            write!(f, "\n  {}..{} => {}..{}", segment.start, bound(end), segment.destination, bound(destination_end))?;
+        }
+        Ok(())
+    }
+}
+
+pub struct Almanac {
+    seeds: Vec<u64>,
+    maps: Vec<FoodCategoryMap>,
+}
+
+impl Almanac {
+    // the maps leading from one category to another in order, None when the almanac has no such path
+    fn chain (&self, from: FoodCategory, to: FoodCategory) -> Option<Vec<&FoodCategoryMap>> {
+        let mut chain = Vec::new();
+        let mut category = from;
+        while category != to {
+            // every map is used at most once, so a loop in the almanac can't spin forever
+            if chain.len() == self.maps.len() {
+                return None;
+            }
+            let map = self.maps.iter().find(|m| m.input == category)?;
+            chain.push(map);
+            category = map.output;
+        }
+        Some(chain)
+    }
+
+    // one piecewise map per almanac map along the chain, the pieces `compose` is built from
+    fn stages (&self, from: FoodCategory, to: FoodCategory) -> Result<Vec<PiecewiseMap>> {
+        let chain = self.chain(from, to).with_context(|| format!("the almanac has no maps leading from {} to {}", from, to))?;
+        chain.into_iter().map(PiecewiseMap::from_map).collect()
+    }
+
+    /// A single map for any pair of categories, pairs running against the almanac's maps are inverted
+    pub fn compose (&self, from: FoodCategory, to: FoodCategory) -> Result<PiecewiseMap> {
+        if self.chain(from, to).is_some() {
+            PiecewiseMap::compose_all(from, &self.stages(from, to)?)
+        } else if self.chain(to, from).is_some() {
+            self.compose(to, from)?.invert()
+        } else {
+            bail!("the almanac has no maps leading from {} to {}", from, to)
+        }
+    }
+
+    /// Where a range of `from` numbers ends up as `to`, asked against the almanac's direction
+    /// (`location` to `seed`) that's every range of seeds reaching those locations
+    pub fn query (&self, from: FoodCategory, range: Range<u64>, to: FoodCategory) -> Result<Vec<Range<u64>>> {
+        if self.chain(from, to).is_some() {
+            Ok(merge(self.compose(from, to)?.map_range(range)?))
+        } else if self.chain(to, from).is_some() {
+            self.compose(to, from)?.preimage(range)
+        } else {
+            bail!("the almanac has no maps between {} and {}", from, to)
+        }
+    }
+
+    // part 2 reads the seeds line as (start, length) pairs
+    fn seed_ranges (&self) -> Result<Vec<Range<u64>>> {
+        if !self.seeds.len().is_multiple_of(2) {
+            return Err(AocError::new(format!("seed ranges come in (start, length) pairs, got {} numbers", self.seeds.len())).into());
+        }
+        let ranges = self.seeds.chunks(2)
+            .map(|pair| {
+                let end = pair[0].checked_add(pair[1])
+                    .ok_or_else(|| AocError::new(format!("seed range {} + {} overflows a 64-bit number", pair[0], pair[1])))?;
+                Ok(pair[0]..end)
+            })
+            .collect::<Result<Vec<_>, AocError>>()?;
+        Ok(ranges.into_iter().filter(|range| !range.is_empty()).collect())
+    }
+}
+
+pub fn parse_category (name: &str) -> Result<FoodCategory> {
+    FoodCategory::from_str(name.trim()).map_err(|_| anyhow!("unknown food category '{}'", name.trim()))
+}
+
+/// `location=46` or a range like `location=46..50`
+pub fn parse_query (spec: &str) -> Result<(FoodCategory, Range<u64>)> {
+    let (category, value) = spec.split_once('=')
+        .with_context(|| format!("expected category=number like location=46, got '{}'", spec))?;
+    let number = |n: &str| n.trim().parse::<u64>().with_context(|| format!("invalid number '{}' in '{}'", n.trim(), spec));
+    let range = match value.split_once("..") {
+        Some((start, end)) => number(start)?..number(end)?,
+        None => {
+            let n = number(value)?;
+            n..n.checked_add(1).with_context(|| format!("{} is past the end of u64", n))?
+        }
+    };
+    if range.is_empty() {
+        bail!("'{}' is an empty range", spec);
+    }
+    Ok((parse_category(category)?, range))
+}
+
+fn render_range (range: &Range<u64>) -> String {
+    if range.end - range.start == 1 {
+        range.start.to_string()
+    } else {
+        format!("{}..{}", range.start, range.end)
+    }
+}
+
+// "location 46" then one line per matching range, "  seed 82"
+pub fn render_query (from: FoodCategory, range: &Range<u64>, to: FoodCategory, results: &[Range<u64>]) -> String {
+    let mut lines = vec![format!("{} {}", from, render_range(range))];
+    if results.is_empty() {
+        lines.push(format!("  no {}", to));
+    }
+    lines.extend(results.iter().map(|r| format!("  {} {}", to, render_range(r))));
+    lines.join("\n")
+}
+
+// what each stage did to a number, e.g. "seed 79 => 81 soil, soil 81 => 81 fertilizer"
+fn history (stages: &[PiecewiseMap], number: u64) -> String {
+    let mut steps = Vec::new();
+    let mut value = number;
+    for stage in stages {
+        let output = stage.get(value);
+        steps.push(format!("{} {} => {} {}", stage.from, value, output, stage.to));
+        value = output;
+    }
+    steps.join(", ")
+}
+
+// part 2 the other way round, independent of the composed map: walks location windows upwards, pulls each one back
+// through the maps to seeds, and the first window any listed seed reaches holds the lowest location.
+// Too slow to run alongside part 2, tests/almanac.rs checks the two agree
+pub fn lowest_location_reverse (almanac: &Almanac) -> Result<u64> {
+    let chain = almanac.chain(FoodCategory::Seed, FoodCategory::Location).context("the almanac has no maps leading from seed to location")?;
+    let seed_ranges = almanac.seed_ranges()?;
+
+    // windows split where the last map's destinations start and end, any split gives the same answer
+    let mut bounds = vec![0, u64::MAX];
+    if let Some(last) = chain.last() {
+        for dm in &last.direct_maps {
+            bounds.push(dm.destination_start);
+            bounds.push(dm.destination_start.saturating_add(dm.range_length));
+        }
+    }
+    bounds.sort();
+    bounds.dedup();
+
+    for window in bounds.windows(2) {
+        cancel::check()?;
+        let window: Range<u64> = window[0]..window[1];
+        let mut sources = vec![window];
+        for map in chain.iter().rev() {
+            sources = sources.into_iter().map(|r| map.preimage(r)).collect::<Result<Vec<_>>>()?.concat();
+        }
+
+        let mut lowest: Option<u64> = None;
+        for source in &sources {
+            for seeds in &seed_ranges {
+                let overlap: Range<u64> = source.start.max(seeds.start)..source.end.min(seeds.end);
+                if overlap.is_empty() {
+                    continue;
+                }
+                let mut locations = vec![overlap];
+                for map in &chain {
+                    locations = locations.into_iter().map(|r| map.map_range(r)).collect::<Result<Vec<_>>>()?.concat();
+                }
+                lowest = lowest.into_iter().chain(locations.iter().map(|r| r.start)).min();
+            }
+        }
+        if let Some(lowest) = lowest {
+            return Ok(lowest);
+        }
+    }
+    bail!("none of the seeds reach a location")
+}
+
+const EXAMPLE: &str = "\
+seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Day5 {}
+register_day!(2023, 5, "If You Give A Seed A Fertilizer", ["almanac"], Day5 {});
+
+impl Solveable for Day5 {
+    type Input = Almanac;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example { input: EXAMPLE, part_1: Some("35"), part_2: Some("46") },
+    ];
+
+    fn parse(&self, lines: &[String]) -> Result<Self::Input> {
+        let seeds_pattern = Regex::new(r"^seeds:(.*)").context("invalid seeds line pattern")?;
+        let map_header_pattern = Regex::new(r"(\w+)-to-(\w+)").context("invalid map header pattern")?;
+        let map_pattern = Regex::new(r"^(\d+)\s(\d+)\s(\d+)$").context("invalid map pattern")?;
+        let number_pattern = Regex::new(r"\d+").context("invalid number pattern")?;
+
+        let mut seeds: Vec<u64> = Vec::new();
+        let mut input_maps: Vec<FoodCategoryMap> = Vec::new();
+
+        for (i, line) in lines.iter().enumerate() {
+            if let Some(number_captures) = map_pattern.captures(line) {
+                let parse_number = |group: usize, what: &str| {
+                    let m = number_captures.get(group).map_or(0..line.len(), |m| m.range());
+                    line[m.clone()].parse::<u64>()
+                        .map_err(|e| AocError::new(format!("failed to parse {}: {}", what, e)).at_line(i, line).span(m))
+                };
+                let destination_start = parse_number(1, "destination")?;
+                let source_start = parse_number(2, "source")?;
+                let range_length = parse_number(3, "range length")?;
+
+                if let Some(cat) = input_maps.last_mut() {
+                    cat.direct_maps.push(MapExpression { source_start, destination_start, range_length, line: i, text: line.clone() })
+                } else {
+                    return Err(AocError::new("encountered map line before map header").at_line(i, line).into());
+                }
+            } else if let Some(head_captures) = map_header_pattern.captures(line) {
+                let parse_category = |group: usize| {
+                    let m = head_captures.get(group).map_or(0..line.len(), |m| m.range());
+                    FoodCategory::from_str(&line[m.clone()])
+                        .map_err(|_| AocError::new(format!("unknown food category '{}'", &line[m.clone()])).at_line(i, line).span(m))
+                };
+                let next_map = FoodCategoryMap {
+                    input: parse_category(1)?,
+                    output: parse_category(2)?,
+                    direct_maps: Vec::new()
+                };
+                input_maps.push(next_map);
+
+            } else if let Some(seed_captures) = seeds_pattern.captures(line) {
+                let offset = seed_captures.get(1).map_or(0, |m| m.start());
+                let (_, [seed_numbers]) = seed_captures.extract();
+                let mut seed_numbers: Vec<u64> = number_pattern
+                    .find_iter(seed_numbers)
+                    .map(|s| {
+                        s.as_str().parse::<u64>().map_err(|e| {
+                            AocError::new(format!("failed to parse seed number: {}", e)).at_line(i, line).span(offset + s.start()..offset + s.end())
+                        })
+                    })
+                    .collect::<Result<Vec<_>, AocError>>()?;
+                seeds.append(&mut seed_numbers);
+            }
+        }
+
+        Ok(Almanac { seeds, maps: input_maps })
+    }
+
+    fn part_1(&self, almanac: &Self::Input) -> Result<Answer> {
+        // the stages are composed once, every seed is then a single lookup and its explanation walks the same stages
+        let stages = almanac.stages(FoodCategory::Seed, FoodCategory::Location)?;
+        let locations = PiecewiseMap::compose_all(FoodCategory::Seed, &stages)?;
+        // (location, seed) of the closest seed so far
+        let mut lowest: Option<(u64, u64)> = None;
+
+        for &seed in &almanac.seeds {
+            cancel::check()?;
+            let location = locations.get(seed);
+            debug!(seed, location, "mapped seed");
+            explain!(format!("seed {}", seed), "{}", history(&stages, seed));
+
+            if lowest.is_none_or(|(lowest, _)| location < lowest) {
+                lowest = Some((location, seed));
+            }
+        }
+
+        let (location, seed) = lowest.context("the almanac lists no seeds")?;
+        explain!("lowest location", "{} from seed {}", location, seed);
+        Ok(location.into())
+    }
+
+    fn part_2(&self, almanac: &Self::Input) -> Result<Answer> {
+        // far too many seeds to walk one by one, so whole ranges go through the composed map
+        let seed_ranges = almanac.seed_ranges()?;
+        let stages = almanac.stages(FoodCategory::Seed, FoodCategory::Location)?;
+        let locations = PiecewiseMap::compose_all(FoodCategory::Seed, &stages)?;
+        let mut lowest: Option<u64> = None;
+
+        for seeds in &seed_ranges {
+            cancel::check()?;
+            let mapped = locations.map_range(seeds.clone())?;
+            let closest = mapped.iter().map(|r| r.start).min();
+            debug!(start = seeds.start, end = seeds.end, pieces = mapped.len(), "mapped seed range");
+            lowest = lowest.into_iter().chain(closest).min();
+        }
+
+        // the composed map skips the categories in between, so the explanation takes the ranges through each stage
+        if explain::enabled() {
+            let mut ranges = seed_ranges;
+            for stage in &stages {
+                ranges = merge(ranges.into_iter().map(|r| stage.map_range(r)).collect::<Result<Vec<_>>>()?.concat());
+                explain!(
+                    format!("{} to {}", stage.from, stage.to),
+                    "{}",
+                    ranges.iter().map(|r| format!("{}..{}", r.start, r.end)).collect::<Vec<_>>().join(", ")
+                );
+            }
+        }
+
+        let lowest = lowest.context("the almanac lists no seeds")?;
+        explain!("lowest location", "{}", lowest);
+        Ok(lowest.into())
+    }
+}
@@ -0,0 +1,133 @@
+use aoc23_rust::{
+    aoc::{Answer, Solveable},
+    error::AocError,
+    y2023::day5::{self, Almanac, CategoryMapper, Day5, FoodCategory},
+};
+
+const CHAIN: [&str; 8] = ["seed", "soil", "fertilizer", "water", "light", "temperature", "humidity", "location"];
+
+// each map's (destination, source, length) lines
+type Maps = Vec<Vec<(u64, u64, u64)>>;
+
+// a small almanac from a fixed seed: its seed ranges, its maps and the almanac text
+fn generated (mut state: u64) -> (Vec<(u64, u64)>, Maps, String) {
+    let mut next = |bound: u64| {
+        state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        (state >> 33) % bound
+    };
+
+    let seeds: Vec<(u64, u64)> = (0..3).map(|_| (next(150), 1 + next(30))).collect();
+    let mut maps = Vec::new();
+    for _ in 1..CHAIN.len() {
+        // sources never overlap, destinations land wherever
+        let mut cuts: Vec<u64> = (0..8).map(|_| next(200)).collect();
+        cuts.sort();
+        cuts.dedup();
+        let mut lines = Vec::new();
+        for w in cuts.windows(2) {
+            if next(3) > 0 {
+                lines.push((next(200), w[0], w[1] - w[0]));
+            }
+        }
+        maps.push(lines);
+    }
+
+    let mut text = format!("seeds: {}\n", seeds.iter().map(|(s, l)| format!("{} {}", s, l)).collect::<Vec<_>>().join(" "));
+    for (names, lines) in CHAIN.windows(2).zip(&maps) {
+        text += &format!("\n{}-to-{} map:\n", names[0], names[1]);
+        for (destination, source, length) in lines {
+            text += &format!("{} {} {}\n", destination, source, length);
+        }
+    }
+    (seeds, maps, text)
+}
+
+// every seed walked through every map, the slowest and plainest way there is
+fn brute_force (seeds: &[(u64, u64)], maps: &Maps) -> u64 {
+    seeds.iter()
+        .flat_map(|&(start, length)| start..start + length)
+        .map(|seed| maps.iter().fold(seed, |value, lines| {
+            lines.iter()
+                .find(|&&(_, source, length)| (source..source + length).contains(&value))
+                .map_or(value, |&(destination, source, _)| destination + value - source)
+        }))
+        .min()
+        .unwrap()
+}
+
+fn example () -> Almanac {
+    Day5 {}.parse(&Day5::EXAMPLES[0].lines()).unwrap()
+}
+
+#[test]
+fn composed_chain_matches_the_walk() {
+    let almanac = example();
+    let locations = almanac.compose(FoodCategory::Seed, FoodCategory::Location).unwrap();
+    let mapped: Vec<u64> = [79, 14, 55, 13].iter().map(|&seed| locations.get(seed)).collect();
+    assert_eq!(mapped, [82, 43, 86, 35]);
+    assert_eq!(locations.map_range(79..93).unwrap(), [82..85, 46..56, 60..61]);
+    let printed = locations.to_string();
+    assert!(printed.starts_with("seed to location\n  0..14 => 22..36\n  14..15 => 43..44\n"));
+    assert!(printed.ends_with("\n  100.. => 100.."));
+
+    // any pair along the chain, and against it through the inverse
+    let humidity = almanac.compose(FoodCategory::Soil, FoodCategory::Humidity).unwrap();
+    assert_eq!(humidity.get(81), 78);
+    let seeds = almanac.compose(FoodCategory::Location, FoodCategory::Seed).unwrap();
+    assert_eq!(seeds, locations.invert().unwrap());
+    assert_eq!(seeds.get(82), 79);
+    assert_eq!(seeds.invert().unwrap(), locations);
+}
+
+#[test]
+fn query_finds_every_preimage() {
+    let almanac = example();
+    let (from, range) = day5::parse_query("location=40..50").unwrap();
+    let to = day5::parse_category("seed").unwrap();
+    let seeds = almanac.query(from, range.clone(), to).unwrap();
+    assert_eq!(seeds, [14..15, 19..22, 52..54, 82..86]);
+    assert_eq!(day5::render_query(from, &range, to, &seeds), "location 40..50\n  seed 14\n  seed 19..22\n  seed 52..54\n  seed 82..86");
+
+    // back the way it came
+    let locations = almanac.compose(FoodCategory::Seed, FoodCategory::Location).unwrap();
+    let mut forward: Vec<u64> = seeds.iter().flat_map(|r| r.clone()).map(|seed| locations.get(seed)).collect();
+    forward.sort();
+    assert_eq!(forward, (40..50).collect::<Vec<_>>());
+    assert!(day5::parse_query("location=7..7").is_err());
+}
+
+#[test]
+fn overflow_points_at_the_map_line() {
+    let lines: Vec<String> = ["seeds: 79 14", "", "seed-to-location map:", "18446744073709551614 18446744073709551600 100"]
+        .iter().map(|l| l.to_string()).collect();
+    let almanac = Day5 {}.parse(&lines).unwrap();
+    let error = Day5 {}.part_1(&almanac).unwrap_err();
+    let error = error.downcast_ref::<AocError>().unwrap();
+    assert_eq!(error.message, "source range end 18446744073709551600 + 100 overflows a 64-bit number");
+    assert_eq!(error.line, Some(4));
+    assert_eq!(error.text.as_deref(), Some(lines[3].as_str()));
+
+    // the destination side too, and part 2 reports it just the same
+    let lines: Vec<String> = ["seeds: 79 14", "", "seed-to-location map:", "18446744073709551600 0 100"]
+        .iter().map(|l| l.to_string()).collect();
+    let almanac = Day5 {}.parse(&lines).unwrap();
+    let error = Day5 {}.part_2(&almanac).unwrap_err();
+    let error = error.downcast_ref::<AocError>().unwrap();
+    assert_eq!(error.message, "destination 18446744073709551600 + 99 overflows a 64-bit number");
+    assert_eq!(error.line, Some(4));
+}
+
+#[test]
+fn reverse_search_agrees_with_part_2() {
+    let almanac = example();
+    assert_eq!(day5::lowest_location_reverse(&almanac).unwrap(), 46);
+
+    for seed in 1..=40 {
+        let (seeds, maps, text) = generated(seed);
+        let lines: Vec<String> = text.lines().map(|l| l.to_string()).collect();
+        let almanac = Day5 {}.parse(&lines).unwrap();
+        let expected = brute_force(&seeds, &maps);
+        assert_eq!(Day5 {}.part_2(&almanac).unwrap(), Answer::Unsigned(expected), "almanac {}:\n{}", seed, text);
+        assert_eq!(day5::lowest_location_reverse(&almanac).unwrap(), expected, "almanac {}:\n{}", seed, text);
+    }
+}
@@ -0,0 +1,114 @@
+use std::fs;
+
+use aoc23_rust::{
+    aoc::{Answer, Solveable},
+    answers::{self, AnswerStore},
+    input,
+    registry,
+    runner::{self, Limits, Status},
+    y2023::day2::Day2,
+};
+
+fn example () -> (String, Vec<String>) {
+    let example = &Day2::EXAMPLES[0];
+    (example.input.to_string() + "\n", example.lines())
+}
+
+#[test]
+fn store_round_trip() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("answers.json");
+    let mut store = AnswerStore::default();
+
+    assert!(store.record(2023, 2, "abc", Some("input/2023/day2.txt"), 1, &Answer::Unsigned(8)).unwrap());
+    assert!(!store.record(2023, 2, "abc", None, 2, &Answer::Unsolved).unwrap());
+    assert!(!store.record(2023, 2, "abc", None, 2, &Answer::Error("bad".to_string())).unwrap());
+    store.save(&path).unwrap();
+
+    let store = AnswerStore::load(&path).unwrap();
+    assert_eq!(store.expected(2023, 2, "abc", 1), Some(&Answer::Unsigned(8)));
+    assert_eq!(store.expected(2023, 2, "abc", 2), None);
+    let recorded = store.recorded(2023);
+    assert_eq!(recorded.len(), 1);
+    assert_eq!((recorded[0].0, recorded[0].1), (2, "abc"));
+    assert_eq!(recorded[0].2.source.as_deref(), Some("input/2023/day2.txt"));
+    assert_eq!(recorded[0].2.parts(), [1]);
+    assert!(store.recorded(2022).is_empty());
+}
+
+#[test]
+fn record_refuses_a_different_answer() {
+    let mut store = AnswerStore::default();
+    store.record(2023, 5, "abc", None, 1, &Answer::Unsigned(35)).unwrap();
+    assert!(store.record(2023, 5, "abc", None, 1, &Answer::Unsigned(35)).unwrap());
+    let error = store.record(2023, 5, "abc", None, 1, &Answer::Unsigned(36)).unwrap_err();
+    assert_eq!(error.to_string(), "day 5 part 1 is recorded as 35 for input abc, not 36");
+    assert_eq!(store.expected(2023, 5, "abc", 1), Some(&Answer::Unsigned(35)));
+
+    // a run only replaces it when asked to
+    let lines = Day2::EXAMPLES[0].lines();
+    let id = answers::input_id(&lines);
+    store.record(2023, 2, &id, None, 1, &Answer::Unsigned(9)).unwrap();
+    let run = runner::run_day(registry::find(2023, "2").unwrap(), &lines, &[1, 2]);
+    let error = runner::record(&mut store, &run, None, false).unwrap_err();
+    assert!(error.to_string().contains("recorded as 9"), "{}", error);
+    assert_eq!(runner::record(&mut store, &run, None, true).unwrap(), 2);
+    assert_eq!(store.expected(2023, 2, &id, 1), Some(&Answer::Unsigned(8)));
+
+    assert_eq!(store.forget(2023, 2, &id, 2), Some(Answer::Unsigned(2286)));
+    assert_eq!(store.expected(2023, 2, &id, 2), None);
+}
+
+#[test]
+fn recording_an_edited_input_drops_its_old_answers() {
+    let mut store = AnswerStore::default();
+    store.record(2023, 5, "old", Some("input/2023/day5.txt"), 1, &Answer::Unsigned(35)).unwrap();
+    store.record(2023, 5, "other", Some("elsewhere.txt"), 1, &Answer::Unsigned(12)).unwrap();
+    store.record(2023, 5, "piped", Some("<stdin>"), 1, &Answer::Unsigned(7)).unwrap();
+    store.record(2023, 5, "new", Some("input/2023/day5.txt"), 1, &Answer::Unsigned(40)).unwrap();
+    store.record(2023, 5, "piped again", Some("<stdin>"), 1, &Answer::Unsigned(8)).unwrap();
+
+    let ids: Vec<&str> = store.recorded(2023).iter().map(|(_, id, _)| *id).collect();
+    assert_eq!(ids, ["new", "other", "piped", "piped again"]);
+}
+
+#[tokio::test]
+async fn verify_catches_drift() {
+    let dir = tempfile::tempdir().unwrap();
+    let (text, lines) = example();
+    let id = answers::input_id(&lines);
+
+    // recorded from a path outside the input dir, which is where verify has to look
+    let source = dir.path().join("elsewhere.txt");
+    fs::write(&source, &text).unwrap();
+    let mut store = AnswerStore::default();
+    store.record(2023, 2, &id, source.to_str(), 1, &Answer::Unsigned(9)).unwrap();
+    store.record(2023, 2, &id, None, 2, &Answer::Unsigned(2286)).unwrap();
+
+    let checks = runner::verify_recorded(&store, 2023, &dir.path().join("input"), &Limits::default()).await;
+    let statuses: Vec<(u8, Status)> = checks.iter().map(|c| (c.part, c.status())).collect();
+    assert_eq!(statuses, [(1, Status::Wrong), (2, Status::Ok)]);
+    assert_eq!(checks[0].answer, Answer::Unsigned(8));
+}
+
+#[tokio::test]
+async fn verify_fails_without_the_input() {
+    let dir = tempfile::tempdir().unwrap();
+    let input_dir = dir.path().join("input");
+    let (_, lines) = example();
+    let mut store = AnswerStore::default();
+    store.record(2023, 2, &answers::input_id(&lines), None, 1, &Answer::Unsigned(8)).unwrap();
+    store.record(2023, 2, &answers::input_id(&lines), None, 2, &Answer::Unsigned(2286)).unwrap();
+
+    let checks = runner::verify_recorded(&store, 2023, &input_dir, &Limits::default()).await;
+    assert_eq!(checks.len(), 2);
+    assert!(checks.iter().all(|c| c.status() == Status::Error));
+    assert!(matches!(&checks[0].answer, Answer::Error(reason) if reason.contains("missing")));
+
+    // an input at the conventional path that isn't the recorded one counts as changed, not as a match
+    let path = input::input_path(&input_dir, 2023, 2);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(&path, "Game 1: 1 red\n").unwrap();
+    let checks = runner::verify_recorded(&store, 2023, &input_dir, &Limits::default()).await;
+    assert!(matches!(&checks[0].answer, Answer::Error(reason) if reason.contains("changed")));
+}
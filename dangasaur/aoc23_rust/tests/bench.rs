@@ -0,0 +1,61 @@
+use std::time::Duration;
+
+use aoc23_rust::bench::{self, BenchReport, Stats};
+
+fn millis (samples: &[u64]) -> Vec<Duration> {
+    samples.iter().map(|&ms| Duration::from_millis(ms)).collect()
+}
+
+fn stats (median: f64) -> Stats {
+    Stats { min: median, median, mean: median, stddev: 0.0 }
+}
+
+fn report (parse: f64, part_1: Option<f64>, part_2: Option<f64>) -> BenchReport {
+    BenchReport { year: 2023, day: 5, iterations: 10, parse: stats(parse), part_1: part_1.map(stats), part_2: part_2.map(stats) }
+}
+
+#[test]
+fn stats_from_samples() {
+    let odd = Stats::from_samples(&millis(&[3, 1, 2]));
+    assert_eq!((odd.min, odd.median, odd.mean), (1e6, 2e6, 2e6));
+    assert_eq!(odd.stddev, 1e6);
+
+    let even = Stats::from_samples(&millis(&[4, 1, 3, 2]));
+    assert_eq!((even.min, even.median, even.mean), (1e6, 2.5e6, 2.5e6));
+    assert!((even.stddev - (5.0f64 / 3.0).sqrt() * 1e6).abs() < 1e-3);
+
+    let one = Stats::from_samples(&millis(&[7]));
+    assert_eq!((one.min, one.median, one.mean, one.stddev), (7e6, 7e6, 7e6, 0.0));
+}
+
+#[test]
+fn regressions_past_the_threshold() {
+    let baseline = report(100.0, Some(1000.0), Some(50.0));
+
+    // exactly at the threshold is still fine, anything beyond it regresses
+    assert!(bench::compare(&report(110.0, Some(1100.0), Some(50.0)), &baseline, 10.0).is_empty());
+    let regressions = bench::compare(&report(110.1, Some(900.0), Some(80.0)), &baseline, 10.0);
+    let phases: Vec<&str> = regressions.iter().map(|r| r.phase).collect();
+    assert_eq!(phases, ["parse", "part 2"]);
+    assert!((regressions[1].change_pct() - 60.0).abs() < 1e-9);
+    assert!(bench::render_regression(&regressions[1]).ends_with("(+60.0%)"));
+}
+
+#[test]
+fn single_part_baselines() {
+    // only part 1 was saved, so part 2 has nothing to regress against
+    let baseline = report(100.0, Some(1000.0), None);
+    let regressions = bench::compare(&report(100.0, Some(2000.0), Some(1e9)), &baseline, 10.0);
+    let phases: Vec<&str> = regressions.iter().map(|r| r.phase).collect();
+    assert_eq!(phases, ["part 1"]);
+
+    // saving part 2 later keeps part 1's baseline
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("bench/baseline.json");
+    bench::save_baseline(&path, &baseline).unwrap();
+    bench::save_baseline(&path, &report(120.0, None, Some(50.0))).unwrap();
+    let saved = &bench::load_baselines(&path).unwrap()["2023/day5"];
+    assert_eq!(saved.parse.median, 120.0);
+    assert_eq!(saved.part_1.as_ref().map(|s| s.median), Some(1000.0));
+    assert_eq!(saved.part_2.as_ref().map(|s| s.median), Some(50.0));
+}
@@ -0,0 +1,61 @@
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use aoc23_rust::{
+    cancel,
+    runner::{self, Limited, Limits},
+};
+
+// a runaway solver that only stops once its token is cancelled, and says so
+fn spin (stopped: Arc<AtomicBool>) -> impl FnOnce() -> anyhow::Result<u64> + Send + 'static {
+    move || loop {
+        if let Err(cancelled) = cancel::check() {
+            stopped.store(true, Ordering::SeqCst);
+            return Err(cancelled.into());
+        }
+        std::thread::sleep(Duration::from_millis(1));
+    }
+}
+
+async fn wait_for (flag: &AtomicBool) {
+    for _ in 0..500 {
+        if flag.load(Ordering::SeqCst) {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    panic!("solver never saw the cancellation");
+}
+
+#[tokio::test]
+async fn timeout_cancels_the_solver() {
+    let limits = Limits { timeout: Some(Duration::from_millis(50)), ..Default::default() };
+    let stopped = Arc::new(AtomicBool::new(false));
+    let (result, elapsed) = runner::limited(&limits, spin(stopped.clone())).await;
+    assert!(matches!(result, Limited::TimedOut));
+    assert!(elapsed >= Duration::from_millis(50));
+    wait_for(&stopped).await;
+
+    let (result, _) = runner::limited(&limits, || Ok(42)).await;
+    assert!(matches!(result, Limited::Done(Ok(42))));
+    assert!(cancel::check().is_ok(), "outside a limited run nothing is cancelled");
+}
+
+#[tokio::test]
+async fn interrupt_cancels_the_solver() {
+    let limits = Limits::default();
+    let stopped = Arc::new(AtomicBool::new(false));
+    let interrupt = limits.interrupt.clone();
+    tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        interrupt.cancel();
+    });
+    let (result, _) = runner::limited(&limits, spin(stopped.clone())).await;
+    assert!(matches!(result, Limited::Interrupted));
+    wait_for(&stopped).await;
+}
@@ -0,0 +1,44 @@
+use anyhow::anyhow;
+
+use aoc23_rust::{
+    aoc::Answer,
+    error::{self, AocError},
+    registry,
+    runner,
+};
+
+fn at (span: std::ops::Range<usize>) -> AocError {
+    AocError::new("bad number").at_line(2, "é → x7").span(span).locate(2023, 5, Some(1))
+}
+
+#[test]
+fn snippet_points_at_the_span() {
+    let error = at(7..9);
+    assert_eq!(error.snippet().unwrap(), "  |\n3 | é → x7\n  |     ^^");
+    assert_eq!(error.short(), "bad number (day 5, part 1, line 3, columns 5-6)");
+    assert_eq!(error.to_string(), "bad number\n --> day 5, part 1, line 3, columns 5-6\n  |\n3 | é → x7\n  |     ^^");
+    assert_eq!(AocError::new("bad number").snippet(), None);
+}
+
+#[test]
+fn multibyte_spans_count_chars() {
+    // spans that cut into a char back off to its start, anything past the end is clamped to the line
+    assert_eq!(at(1..4).short(), "bad number (day 5, part 1, line 3, columns 1-2)");
+    assert_eq!(at(1..4).snippet().unwrap(), "  |\n3 | é → x7\n  | ^^");
+    assert_eq!(at(5..50).short(), "bad number (day 5, part 1, line 3, columns 3-6)");
+    assert_eq!(at(50..60).short(), "bad number (day 5, part 1, line 3, column 7)");
+}
+
+#[test]
+fn summary_stays_on_one_line() {
+    let error = anyhow!(at(7..9)).context("failed to parse input");
+    assert_eq!(error::summary(&error), "failed to parse input: bad number (day 5, part 1, line 3, columns 5-6)");
+
+    let registration = registry::find(2023, "5").unwrap();
+    let lines: Vec<String> = ["seeds: 79 14", "", "seed-to-soil map:", "50 98 99999999999999999999"].iter().map(|l| l.to_string()).collect();
+    let run = runner::run_day(registration, &lines, &[1, 2]);
+    assert!(run.error.as_deref().unwrap().contains("4 | 50 98 99999999999999999999"));
+    for part in &run.parts {
+        assert!(matches!(&part.answer, Answer::Error(reason) if !reason.contains('\n') && reason.contains("line 4")), "{:?}", part.answer);
+    }
+}
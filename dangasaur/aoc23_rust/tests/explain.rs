@@ -0,0 +1,46 @@
+use aoc23_rust::{aoc::Answer, explain, registry};
+
+#[test]
+fn explains_from_the_solving_path() {
+    let registration = registry::find(2023, "2").unwrap();
+    let lines = registration.solver.examples()[0].lines();
+    let explanation = explain::explain_day(registration, &lines, &[1]).unwrap();
+
+    let part = &explanation.parts[0];
+    assert_eq!(part.answer, Answer::Unsigned(8));
+    assert_eq!(part.steps.len(), 5);
+    assert_eq!(part.steps[2].subject, "game 3");
+    assert!(part.steps[2].detail.contains("impossible"));
+
+    let markdown = explain::render_markdown(&explanation);
+    assert!(markdown.contains("| game 3 | max red 20, green 13, blue 6 => impossible"));
+}
+
+#[test]
+fn records_nothing_outside_an_explanation() {
+    assert!(!explain::enabled());
+    explain::step("ignored", "not recording");
+    let (_, steps) = explain::record(|| explain::step("seed 79", "location 82"));
+    assert_eq!(steps.len(), 1);
+    assert!(!explain::enabled());
+}
+
+#[test]
+fn day5_explains_every_stage() {
+    let registration = registry::find(2023, "5").unwrap();
+    let lines = registration.solver.examples()[0].lines();
+    let explanation = explain::explain_day(registration, &lines, &[1, 2]).unwrap();
+
+    let part_1 = &explanation.parts[0];
+    assert_eq!(part_1.answer, Answer::Unsigned(35));
+    assert_eq!(part_1.steps[3].subject, "seed 13");
+    assert!(part_1.steps[3].detail.starts_with("seed 13 => 13 soil, soil 13 => 52 fertilizer"));
+    assert!(part_1.steps[3].detail.ends_with("humidity 35 => 35 location"));
+
+    let part_2 = &explanation.parts[1];
+    assert_eq!(part_2.answer, Answer::Unsigned(46));
+    let subjects: Vec<&str> = part_2.steps.iter().map(|s| s.subject.as_str()).collect();
+    assert_eq!(subjects[0], "seed to soil");
+    assert_eq!(subjects[6], "humidity to location");
+    assert_eq!(part_2.steps[6].detail, "46..61, 82..85, 86..90, 94..99");
+}
@@ -0,0 +1,50 @@
+use std::fs;
+
+use aoc23_rust::{client::{AocClient, USER_AGENT}, input};
+use wiremock::{
+    matchers::{header, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+#[tokio::test]
+async fn fetch_downloads_and_caches_input() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/2023/day/5/input"))
+        .and(header("cookie", "session=abc123"))
+        .and(header("user-agent", USER_AGENT))
+        .respond_with(ResponseTemplate::new(200).set_body_string("seeds: 79 14 55 13\n"))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let input_dir = tempfile::tempdir().unwrap();
+    let client = AocClient::new(&server.uri(), "abc123").unwrap();
+
+    let (path, downloaded) = input::fetch_input(&client, input_dir.path(), 2023, 5).await.unwrap();
+    assert!(downloaded);
+    assert_eq!(path, input_dir.path().join("2023").join("day5.txt"));
+    assert_eq!(fs::read_to_string(&path).unwrap(), "seeds: 79 14 55 13\n");
+
+    // the mock only allows one request, a second fetch has to come from the cache
+    let (cached, downloaded) = input::fetch_input(&client, input_dir.path(), 2023, 5).await.unwrap();
+    assert!(!downloaded);
+    assert_eq!(cached, path);
+}
+
+#[tokio::test]
+async fn fetch_failure_leaves_nothing_cached() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/2023/day/6/input"))
+        .respond_with(ResponseTemplate::new(400).set_body_string("Puzzle inputs differ by user.  Please log in to get your puzzle input."))
+        .mount(&server)
+        .await;
+
+    let input_dir = tempfile::tempdir().unwrap();
+    let client = AocClient::new(&server.uri(), "expired").unwrap();
+
+    let error = input::fetch_input(&client, input_dir.path(), 2023, 6).await.unwrap_err();
+    assert!(format!("{:#}", error).contains("400"));
+    assert!(!input::input_path(input_dir.path(), 2023, 6).exists());
+}
@@ -0,0 +1,52 @@
+use std::{
+    env, fs,
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use flate2::{write::GzEncoder, Compression};
+
+use aoc23_rust::input::{self, InputSource};
+
+fn source () -> InputSource {
+    InputSource::File(PathBuf::from("test.txt"))
+}
+
+#[test]
+fn decodes_gzip_crlf_and_bom() {
+    let expected = vec!["seeds: 79 14".to_string(), String::new(), "50 98 2".to_string()];
+    assert_eq!(input::decode(b"seeds: 79 14\n\n50 98 2\n".to_vec(), &source()).unwrap(), expected);
+    assert_eq!(input::decode(b"\xef\xbb\xbfseeds: 79 14\r\n\r\n50 98 2\r\n".to_vec(), &source()).unwrap(), expected);
+
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(b"seeds: 79 14\r\n\r\n50 98 2").unwrap();
+    assert_eq!(input::decode(encoder.finish().unwrap(), &source()).unwrap(), expected);
+
+    let error = input::decode(vec![0xff, 0xfe, b'a'], &source()).unwrap_err();
+    assert_eq!(error.to_string(), "test.txt is not valid UTF-8");
+    assert!(input::decode(vec![0x1f, 0x8b, 0x00], &source()).is_err());
+}
+
+#[test]
+fn resolves_home_and_the_input_dir() {
+    let dir = tempfile::tempdir().unwrap();
+    let input_dir = dir.path().join("input");
+    fs::create_dir_all(input_dir.join("2023")).unwrap();
+    fs::write(input_dir.join("2023/day5.txt"), "seeds: 1 2\n").unwrap();
+
+    // relative paths that don't exist from the cwd fall back to the input dir
+    assert_eq!(input::resolve_path(Path::new("2023/day5.txt"), &input_dir), input_dir.join("2023/day5.txt"));
+    assert_eq!(input::resolve_path(Path::new("2023/day6.txt"), &input_dir), PathBuf::from("2023/day6.txt"));
+    assert_eq!(input::resolve_path(&input_dir.join("2023/day6.txt"), &input_dir), input_dir.join("2023/day6.txt"));
+
+    if let Some(home) = env::var_os("HOME") {
+        assert_eq!(input::resolve_path(Path::new("~/aoc/day1.txt"), &input_dir), PathBuf::from(home).join("aoc/day1.txt"));
+    }
+}
+
+#[test]
+fn dash_means_stdin() {
+    assert_eq!(InputSource::from_arg(Path::new("-"), "input"), InputSource::Stdin);
+    assert_eq!(InputSource::Stdin.to_string(), "<stdin>");
+    assert_eq!(InputSource::from_arg(Path::new("no/such/file.txt"), "input"), InputSource::File(PathBuf::from("no/such/file.txt")));
+}
@@ -0,0 +1,19 @@
+use aoc23_rust::registry;
+
+#[test]
+fn day_part_addressing() {
+    assert_eq!(registry::split_part("5").unwrap(), ("5", &[1, 2][..]));
+    assert_eq!(registry::split_part("day5.1").unwrap(), ("day5", &[1][..]));
+    assert_eq!(registry::split_part("almanac.2").unwrap(), ("almanac", &[2][..]));
+    assert_eq!(registry::split_part("1..5").unwrap(), ("1..5", &[1, 2][..]));
+    assert!(registry::split_part("5.3").is_err());
+
+    let selected = registry::select(2023, "2.1").unwrap();
+    assert_eq!(selected.len(), 1);
+    assert_eq!((selected[0].registration.day, selected[0].parts), (2, &[1][..]));
+
+    let range = registry::select(2023, "2..=4").unwrap();
+    let days: Vec<u32> = range.iter().map(|s| s.registration.day).collect();
+    assert_eq!(days, [2, 3, 4]);
+    assert!(range.iter().all(|s| s.parts == [1, 2]));
+}
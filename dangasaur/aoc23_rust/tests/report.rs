@@ -0,0 +1,48 @@
+use std::time::Duration;
+
+use aoc23_rust::{
+    aoc::Answer,
+    report,
+    runner::{DayRun, PartRun},
+};
+
+fn run() -> DayRun {
+    DayRun {
+        year: 2023,
+        day: 5,
+        title: "If You Give A Seed A Fertilizer",
+        input_id: Some("0123456789abcdef".to_string()),
+        parse_elapsed: Duration::from_micros(20),
+        parts: vec![
+            PartRun { part: 1, answer: Answer::Unsigned(35), elapsed: Duration::from_nanos(1500), error: None },
+            PartRun { part: 2, answer: Answer::Error("no map, \"soil\"".to_string()), elapsed: Duration::ZERO, error: None },
+        ],
+        error: None,
+    }
+}
+
+#[test]
+fn one_record_per_part() {
+    let records = report::records(&[run()]);
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0].answer, Answer::Unsigned(35));
+    assert_eq!(records[0].answer_type, "unsigned");
+    assert_eq!(records[0].elapsed_ns, 1500);
+    assert_eq!(records[0].parse_ns, 20_000);
+    assert_eq!(records[1].answer_type, "error");
+    assert_eq!(records[1].version, env!("CARGO_PKG_VERSION"));
+}
+
+#[test]
+fn csv_and_json_share_the_schema() {
+    let csv = report::render_csv(&[run()]).unwrap();
+    let mut lines = csv.lines();
+    assert_eq!(lines.next(), Some("year,day,part,answer,answer_type,elapsed_ns,parse_ns,input_hash,version"));
+    assert!(lines.next().unwrap().starts_with("2023,5,1,35,unsigned,1500,20000,0123456789abcdef,"));
+    assert!(lines.next().unwrap().starts_with(r#"2023,5,2,"no map, ""soil""",error,"#));
+
+    let json: serde_json::Value = serde_json::from_str(&report::render_json(&[run()]).unwrap()).unwrap();
+    assert_eq!(json[0]["answer"], 35);
+    assert_eq!(json[1]["answer"]["error"], "no map, \"soil\"");
+    assert_eq!(json[1]["input_hash"], "0123456789abcdef");
+}
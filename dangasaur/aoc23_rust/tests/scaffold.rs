@@ -0,0 +1,38 @@
+use std::fs;
+
+use aoc23_rust::scaffold;
+
+#[test]
+fn generates_and_declares_a_new_day() {
+    let root = tempfile::tempdir().unwrap();
+    let src = root.path().join("src");
+    fs::create_dir_all(src.join("y2023")).unwrap();
+    fs::write(src.join("lib.rs"), "pub mod aoc;\npub mod y2023;\n").unwrap();
+    fs::write(src.join("y2023").join("mod.rs"), "pub mod day1;\npub mod day5;\npub mod day12;\n").unwrap();
+    let input_dir = root.path().join("input");
+
+    scaffold::new_day(root.path(), &input_dir, 2023, 6, "Wait For It").unwrap();
+
+    let module = fs::read_to_string(src.join("y2023").join("day6.rs")).unwrap();
+    assert!(module.contains(r#"register_day!(2023, 6, "Wait For It", [], Day6 {});"#));
+    assert!(module.contains(r#"include_str!("../../samples/2023/day6.txt")"#));
+    assert_eq!(fs::read_to_string(src.join("y2023").join("mod.rs")).unwrap(), "pub mod day1;\npub mod day5;\npub mod day6;\npub mod day12;\n");
+    assert!(root.path().join("samples").join("2023").join("day6.txt").exists());
+    assert!(input_dir.join("2023").join("day6.txt").exists());
+
+    let again = scaffold::new_day(root.path(), &input_dir, 2023, 6, "Wait For It");
+    assert!(again.unwrap_err().to_string().contains("refusing to overwrite"));
+}
+
+#[test]
+fn starts_a_new_year() {
+    let root = tempfile::tempdir().unwrap();
+    let src = root.path().join("src");
+    fs::create_dir_all(&src).unwrap();
+    fs::write(src.join("lib.rs"), "pub mod aoc;\npub mod y2023;\n").unwrap();
+
+    scaffold::new_day(root.path(), &root.path().join("input"), 2024, 1, "Day 1").unwrap();
+
+    assert_eq!(fs::read_to_string(src.join("lib.rs")).unwrap(), "pub mod aoc;\npub mod y2023;\npub mod y2024;\n");
+    assert_eq!(fs::read_to_string(src.join("y2024").join("mod.rs")).unwrap(), "pub mod day1;\n");
+}
@@ -0,0 +1,98 @@
+use aoc23_rust::{
+    aoc::Answer,
+    client::AocClient,
+    submit::{self, GuessLog, Verdict},
+};
+use wiremock::{
+    matchers::{body_string, header, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn page(article: &str) -> String {
+    format!("<html><body><main><article><p>{}</p></article></main></body></html>", article)
+}
+
+const CORRECT: &str = "That's the right answer! You are one gold star closer to restoring snow operations. <a href=\"/2023/day/5\">[Return to Day 5]</a>";
+const TOO_HIGH: &str = "That's not the right answer; your answer is too high. If you're stuck, make sure you're using the full input data. Please wait one minute before trying again. <a href=\"/2023/day/5\">[Return to Day 5]</a>";
+const TOO_LOW: &str = "That's not the right answer; your answer is too low. Please wait 5 minutes before trying again.";
+const TOO_RECENT: &str = "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 23s left to wait. <a href=\"/2023/day/5\">[Return to Day 5]</a>";
+const WRONG_LEVEL: &str = "You don't seem to be solving the right level.  Did you already complete it?";
+
+#[test]
+fn parses_result_pages() {
+    assert_eq!(submit::parse_response(&page(CORRECT)).unwrap(), Verdict::Correct);
+    assert_eq!(submit::parse_response(&page(TOO_HIGH)).unwrap(), Verdict::TooHigh);
+    assert_eq!(submit::parse_response(&page(TOO_LOW)).unwrap(), Verdict::TooLow);
+    assert_eq!(submit::parse_response(&page(TOO_RECENT)).unwrap(), Verdict::RateLimited { wait_secs: 83 });
+    assert_eq!(submit::parse_response(&page(WRONG_LEVEL)).unwrap(), Verdict::AlreadySolved);
+    assert_eq!(submit::parse_wait(&page(TOO_LOW)).unwrap().map(|w| w.as_secs()), Some(300));
+    assert!(matches!(submit::parse_response("<html>maintenance</html>").unwrap(), Verdict::Unknown(_)));
+}
+
+#[test]
+fn refuses_known_bad_and_bounded_answers() {
+    let mut log = GuessLog::default();
+    log.record(2023, 5, 1, &Answer::Unsigned(100), &Verdict::TooHigh, None);
+    log.record(2023, 5, 1, &Answer::Unsigned(10), &Verdict::TooLow, None);
+
+    assert!(log.check(2023, 5, 1, &Answer::Unsigned(100)).is_err());
+    assert!(log.check(2023, 5, 1, &Answer::Unsigned(150)).is_err());
+    assert!(log.check(2023, 5, 1, &Answer::Unsigned(10)).is_err());
+    assert!(log.check(2023, 5, 1, &Answer::Unsigned(42)).is_ok());
+    // bounds only apply to the part they were learned on
+    assert!(log.check(2023, 5, 2, &Answer::Unsigned(150)).is_ok());
+}
+
+#[tokio::test]
+async fn submits_to_stand_in_server() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/2023/day/5/answer"))
+        .and(header("cookie", "session=abc123"))
+        .and(body_string("level=1&answer=200"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(page(TOO_HIGH)))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/2023/day/5/answer"))
+        .and(body_string("level=1&answer=35"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(page(CORRECT)))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = AocClient::new(&server.uri(), "abc123").unwrap();
+    let mut log = GuessLog::default();
+
+    let verdict = submit::submit(&client, &mut log, 2023, 5, 1, &Answer::Unsigned(200)).await.unwrap();
+    assert_eq!(verdict, Verdict::TooHigh);
+    assert!(log.wait_remaining().is_some());
+
+    // the one minute penalty is enforced locally, clear it to keep going
+    log.not_before = 0;
+    assert!(submit::submit(&client, &mut log, 2023, 5, 1, &Answer::Unsigned(250)).await.is_err());
+
+    let verdict = submit::submit(&client, &mut log, 2023, 5, 1, &Answer::Unsigned(35)).await.unwrap();
+    assert_eq!(verdict, Verdict::Correct);
+    assert!(log.check(2023, 5, 1, &Answer::Unsigned(35)).is_err());
+}
+
+#[tokio::test]
+async fn rate_limit_blocks_the_next_submission() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/2023/day/3/answer"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(page(TOO_RECENT)))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = AocClient::new(&server.uri(), "abc123").unwrap();
+    let mut log = GuessLog::default();
+
+    let verdict = submit::submit(&client, &mut log, 2023, 3, 2, &Answer::Unsigned(467835)).await.unwrap();
+    assert_eq!(verdict, Verdict::RateLimited { wait_secs: 83 });
+    assert!(log.guesses(2023, 3, 2).is_empty());
+    assert!(submit::submit(&client, &mut log, 2023, 3, 2, &Answer::Unsigned(467835)).await.is_err());
+}
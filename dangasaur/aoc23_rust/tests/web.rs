@@ -0,0 +1,115 @@
+use std::time::Duration;
+
+use anyhow::Result;
+use aoc23_rust::{
+    aoc::{Answer, Example, Solveable},
+    cancel, register_day,
+    runner::Limits,
+    web::{self, ServeConfig},
+};
+use reqwest::multipart::Form;
+use tokio::net::TcpListener;
+
+// spins on "spin" until it's cancelled, like a solver stuck on a bad input
+struct Runaway {}
+register_day!(1999, 1, "Runaway", [], Runaway {});
+
+impl Solveable for Runaway {
+    type Input = Vec<String>;
+
+    const EXAMPLES: &'static [Example] = &[Example { input: "done", part_1: Some("1"), part_2: None }];
+
+    fn parse (&self, lines: &[String]) -> Result<Self::Input> {
+        Ok(lines.to_vec())
+    }
+
+    fn part_1 (&self, input: &Self::Input) -> Result<Answer> {
+        while input.iter().any(|line| line == "spin") {
+            cancel::check()?;
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        Ok(1u64.into())
+    }
+
+    fn part_2 (&self, _input: &Self::Input) -> Result<Answer> {
+        Ok(Answer::Unsolved)
+    }
+}
+
+async fn start() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    let limits = Limits { timeout: Some(Duration::from_millis(200)), ..Default::default() };
+    let config = ServeConfig { year: 2023, static_dir: "no-static-dir".into(), limits };
+    tokio::spawn(web::serve(listener, config));
+    format!("http://{}", address)
+}
+
+#[tokio::test]
+async fn lists_registered_days() {
+    let base = start().await;
+    let body = reqwest::get(&base).await.unwrap().text().await.unwrap();
+    assert!(body.contains(r#"<a href="/day/2023/2">day 2: Cube Conundrum</a>"#));
+
+    let missing = reqwest::get(format!("{}/day/2023/99", base)).await.unwrap();
+    assert_eq!(missing.status(), 404);
+}
+
+#[tokio::test]
+async fn solves_pasted_input_as_a_fragment_for_htmx() {
+    let base = start().await;
+    let form = Form::new()
+        .text("input", "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green\nGame 2: 20 red")
+        .text("part", "1");
+    let body = reqwest::Client::new()
+        .post(format!("{}/day/2023/2", base))
+        .header("HX-Request", "true")
+        .multipart(form)
+        .send().await.unwrap()
+        .text().await.unwrap();
+
+    assert!(!body.contains("<html"));
+    assert!(body.contains("<strong>1</strong>"));
+    assert!(body.contains("<b>game 2</b>: max red 20, green 0, blue 0 =&gt; impossible"));
+    assert!(!body.contains("part 2 explanation"));
+}
+
+#[tokio::test]
+async fn plain_form_posts_get_the_whole_page_with_input_errors() {
+    let base = start().await;
+    let form = Form::new().text("input", "seeds: 1\n1 2 3");
+    let body = reqwest::Client::new()
+        .post(format!("{}/day/2023/5", base))
+        .multipart(form)
+        .send().await.unwrap()
+        .text().await.unwrap();
+
+    assert!(body.contains("<html"));
+    assert!(body.contains("encountered map line before map header"));
+    assert!(body.contains("line 2"));
+}
+
+#[tokio::test]
+async fn serves_the_built_in_htmx() {
+    let base = start().await;
+    let response = reqwest::get(format!("{}/static/htmx.min.js", base)).await.unwrap();
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.headers()["content-type"], "text/javascript");
+    assert!(response.text().await.unwrap().contains(r#"version:"1.9.3""#));
+
+    let missing = reqwest::get(format!("{}/static/other.js", base)).await.unwrap();
+    assert_eq!(missing.status(), 404);
+}
+
+#[tokio::test]
+async fn a_runaway_solver_times_out() {
+    let base = start().await;
+    let form = Form::new().text("input", "spin").text("part", "1");
+    let body = reqwest::Client::new()
+        .post(format!("{}/day/1999/1", base))
+        .header("HX-Request", "true")
+        .multipart(form)
+        .send().await.unwrap()
+        .text().await.unwrap();
+    assert!(body.contains(r#"<pre class="error">timed out after"#), "{}", body);
+}